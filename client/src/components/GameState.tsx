@@ -3,6 +3,7 @@ import { PlayerInfoTable } from '../generated/player_info_table_type';
 import { useGameStateTable } from '../hooks/useGameStateTable';
 import { Countdown } from './Countdown';
 import { Playing } from './Playing';
+import { ReadyCheck } from './ReadyCheck';
 import { Settings } from './Settings';
 
 interface GameStateProps {
@@ -29,6 +30,12 @@ export function GameState({ game, conn, playerInfos }: GameStateProps) {
   const getCurrentPlayer = () => {
     if (!conn.identity || !gameStateTable?.state) return null;
     switch (gameStateTable.state.tag) {
+      case 'ReadyCheck':
+        return (
+          gameStateTable.state.value.settings.players.find(
+            (p) => p.playerIdentity.toHexString() === conn.identity.toHexString()
+          ) || null
+        );
       case 'Countdown':
         return (
           gameStateTable.state.value.settings.players.find(
@@ -71,6 +78,19 @@ export function GameState({ game, conn, playerInfos }: GameStateProps) {
           />
         </div>
       );
+    case 'ReadyCheck':
+      return (
+        <div>
+          <GameHeader name={game.name} />
+          <ReadyCheck
+            gameId={gameStateTable.gameId}
+            readyCheckState={gameStateTable.state.value}
+            playerInfos={playerInfos}
+            conn={conn}
+            isCurrentPlayer={!!getCurrentPlayer()}
+          />
+        </div>
+      );
     case 'Countdown':
       return (
         <div>
@@ -118,6 +118,9 @@ export function Player({
     case 'UseAllLetters': {
       break;
     }
+    case 'Points': {
+      break;
+    }
     default: {
       const _exhaustive: never = player.winConditionData;
       throw new Error(`Unhandled winConditionData tag: ${JSON.stringify(_exhaustive)}`);
@@ -189,6 +192,16 @@ export function Player({
             }
             case 'UseAllLetters':
               return null;
+            case 'Points': {
+              if (player.winConditionData.tag === 'Points') {
+                return (
+                  <span className="text-yellow-400 font-bold">
+                    {player.winConditionData.value} pts
+                  </span>
+                );
+              }
+              return null;
+            }
             default: {
               // Exhaustiveness check
               const _exhaustive: never = winCondition;
@@ -73,6 +73,14 @@ export function Playing({ playingState, playerInfos, conn, gameId }: PlayingProp
     case 'UseAllLetters':
       winConditionLabel = 'First to use every letter wins!';
       break;
+    case 'Points': {
+      const goal = playingState.settings.winCondition.value;
+      winConditionLabel =
+        goal.tag === 'TargetScore'
+          ? `First to ${goal.value} points wins!`
+          : `Most points after ${goal.value} rounds wins!`;
+      break;
+    }
     default:
       winConditionLabel = '';
   }
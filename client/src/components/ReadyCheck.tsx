@@ -0,0 +1,73 @@
+import { DbConnection } from '../generated';
+import { PlayerInfoTable } from '../generated/player_info_table_type';
+import { ReadyCheckState } from '../generated/ready_check_state_type';
+
+interface ReadyCheckProps {
+  gameId: number;
+  readyCheckState: ReadyCheckState;
+  playerInfos: PlayerInfoTable[];
+  conn: DbConnection;
+  isCurrentPlayer: boolean;
+}
+
+export function ReadyCheck({
+  gameId,
+  readyCheckState,
+  playerInfos,
+  conn,
+  isCurrentPlayer,
+}: ReadyCheckProps) {
+  const isReady = (identityHex: string) =>
+    readyCheckState.readyPlayers.some((identity) => identity.toHexString() === identityHex);
+
+  const isConfirmed = !!conn.identity && isReady(conn.identity.toHexString());
+
+  const handleConfirmReady = async () => {
+    if (!conn) return;
+    try {
+      await conn.reducers.confirmReady(gameId);
+    } catch (error) {
+      // Silently handle errors
+    }
+  };
+
+  return (
+    <div className="text-center space-y-8">
+      <h2 className="text-2xl font-medium">Waiting for everyone to be ready</h2>
+      {isCurrentPlayer && (
+        <button
+          onClick={handleConfirmReady}
+          disabled={isConfirmed}
+          className="bg-green-600 hover:bg-green-700 disabled:bg-gray-600 text-white px-6 py-3 rounded text-lg font-medium"
+        >
+          {isConfirmed ? 'Ready!' : "I'm Ready"}
+        </button>
+      )}
+      <div className="space-y-4">
+        <h2 className="text-2xl font-medium">Players</h2>
+        <div className="grid grid-cols-1 sm:grid-cols-2 gap-2">
+          {readyCheckState.settings.players.map((player) => {
+            const playerInfo = playerInfos.find(
+              (info) => info.identity.toHexString() === player.playerIdentity.toHexString()
+            );
+            if (!playerInfo) return null;
+
+            return (
+              <div
+                key={player.playerIdentity.toHexString()}
+                className="bg-gray-800 p-4 rounded flex items-center gap-2"
+              >
+                <div
+                  className={`w-2 h-2 rounded-full ${
+                    isReady(player.playerIdentity.toHexString()) ? 'bg-green-500' : 'bg-gray-500'
+                  }`}
+                />
+                <span>{playerInfo.username}</span>
+              </div>
+            );
+          })}
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -32,9 +32,14 @@ import {
   type ReducerEventContextInterface,
   type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { TurnDirection as __TurnDirection } from "./turn_direction_type";
+import { TurnEffect as __TurnEffect } from "./turn_effect_type";
+
 export type ClassicTurnLogic = {
   currentTurnIndex: number,
   failedPlayers: Identity[],
+  direction: __TurnDirection,
+  pendingEffect: __TurnEffect | undefined,
 };
 
 /**
@@ -49,6 +54,8 @@ export namespace ClassicTurnLogic {
     return AlgebraicType.createProductType([
       new ProductTypeElement("currentTurnIndex", AlgebraicType.createU32Type()),
       new ProductTypeElement("failedPlayers", AlgebraicType.createArrayType(AlgebraicType.createIdentityType())),
+      new ProductTypeElement("direction", __TurnDirection.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("pendingEffect", AlgebraicType.createOptionType(__TurnEffect.getTypeScriptAlgebraicType())),
     ]);
   }
 
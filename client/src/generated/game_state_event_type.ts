@@ -34,6 +34,9 @@ import {
 } from "@clockworklabs/spacetimedb-sdk";
 import { InvalidGuessEvent as __InvalidGuessEvent } from "./invalid_guess_event_type";
 import { FreeLetterAwardEvent as __FreeLetterAwardEvent } from "./free_letter_award_event_type";
+import { PowerUp as __PowerUp } from "./power_up_type";
+import { PowerUpUsedEvent as __PowerUpUsedEvent } from "./power_up_used_event_type";
+import { Reaction as __Reaction } from "./reaction_type";
 
 // A namespace for generated variants and helper functions.
 export namespace GameStateEvent {
@@ -48,6 +51,14 @@ export namespace GameStateEvent {
   export type CorrectGuess = { tag: "CorrectGuess", value: string };
   export type LifeEarned = { tag: "LifeEarned" };
   export type FreeLetterAward = { tag: "FreeLetterAward", value: __FreeLetterAwardEvent };
+  export type Overtime = { tag: "Overtime" };
+  export type PowerUpAwarded = { tag: "PowerUpAwarded", value: __PowerUp };
+  export type PowerUpUsed = { tag: "PowerUpUsed", value: __PowerUpUsedEvent };
+  export type ShieldBlocked = { tag: "ShieldBlocked" };
+  export type TurnOrderReversed = { tag: "TurnOrderReversed" };
+  export type PlayerSkipped = { tag: "PlayerSkipped", value: Identity };
+  export type BombPassedBack = { tag: "BombPassedBack" };
+  export type Reaction = { tag: "Reaction", value: __Reaction };
 
   // Helper functions for constructing each variant of the tagged union.
   // ```
@@ -63,6 +74,14 @@ export namespace GameStateEvent {
   export const CorrectGuess = (value: string): GameStateEvent => ({ tag: "CorrectGuess", value });
   export const LifeEarned = { tag: "LifeEarned" };
   export const FreeLetterAward = (value: __FreeLetterAwardEvent): GameStateEvent => ({ tag: "FreeLetterAward", value });
+  export const Overtime = { tag: "Overtime" };
+  export const PowerUpAwarded = (value: __PowerUp): GameStateEvent => ({ tag: "PowerUpAwarded", value });
+  export const PowerUpUsed = (value: __PowerUpUsedEvent): GameStateEvent => ({ tag: "PowerUpUsed", value });
+  export const ShieldBlocked = { tag: "ShieldBlocked" };
+  export const TurnOrderReversed = { tag: "TurnOrderReversed" };
+  export const PlayerSkipped = (value: Identity): GameStateEvent => ({ tag: "PlayerSkipped", value });
+  export const BombPassedBack = { tag: "BombPassedBack" };
+  export const Reaction = (value: __Reaction): GameStateEvent => ({ tag: "Reaction", value });
 
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createSumType([
@@ -74,6 +93,14 @@ export namespace GameStateEvent {
       new SumTypeVariant("CorrectGuess", AlgebraicType.createStringType()),
       new SumTypeVariant("LifeEarned", AlgebraicType.createProductType([])),
       new SumTypeVariant("FreeLetterAward", __FreeLetterAwardEvent.getTypeScriptAlgebraicType()),
+      new SumTypeVariant("Overtime", AlgebraicType.createProductType([])),
+      new SumTypeVariant("PowerUpAwarded", __PowerUp.getTypeScriptAlgebraicType()),
+      new SumTypeVariant("PowerUpUsed", __PowerUpUsedEvent.getTypeScriptAlgebraicType()),
+      new SumTypeVariant("ShieldBlocked", AlgebraicType.createProductType([])),
+      new SumTypeVariant("TurnOrderReversed", AlgebraicType.createProductType([])),
+      new SumTypeVariant("PlayerSkipped", AlgebraicType.createIdentityType()),
+      new SumTypeVariant("BombPassedBack", AlgebraicType.createProductType([])),
+      new SumTypeVariant("Reaction", __Reaction.getTypeScriptAlgebraicType()),
     ]);
   }
 
@@ -88,7 +115,7 @@ export namespace GameStateEvent {
 }
 
 // The tagged union or sum type for the algebraic type `GameStateEvent`.
-export type GameStateEvent = GameStateEvent.InvalidGuess | GameStateEvent.TimeUp | GameStateEvent.MyTurn | GameStateEvent.IWin | GameStateEvent.ILose | GameStateEvent.CorrectGuess | GameStateEvent.LifeEarned | GameStateEvent.FreeLetterAward;
+export type GameStateEvent = GameStateEvent.InvalidGuess | GameStateEvent.TimeUp | GameStateEvent.MyTurn | GameStateEvent.IWin | GameStateEvent.ILose | GameStateEvent.CorrectGuess | GameStateEvent.LifeEarned | GameStateEvent.FreeLetterAward | GameStateEvent.Overtime | GameStateEvent.PowerUpAwarded | GameStateEvent.PowerUpUsed | GameStateEvent.ShieldBlocked | GameStateEvent.TurnOrderReversed | GameStateEvent.PlayerSkipped | GameStateEvent.BombPassedBack | GameStateEvent.Reaction;
 
 export default GameStateEvent;
 
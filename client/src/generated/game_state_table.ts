@@ -35,6 +35,7 @@ import {
 import { GameStateTable } from "./game_state_table_type";
 import { GameState as __GameState } from "./game_state_type";
 import { PlayerWins as __PlayerWins } from "./player_wins_type";
+import { SeriesState as __SeriesState } from "./series_state_type";
 
 import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 
@@ -34,12 +34,15 @@ import {
 } from "@clockworklabs/spacetimedb-sdk";
 import { GameState as __GameState } from "./game_state_type";
 import { PlayerWins as __PlayerWins } from "./player_wins_type";
+import { SeriesState as __SeriesState } from "./series_state_type";
 
 export type GameStateTable = {
   gameId: number,
   state: __GameState,
   updatedAt: Timestamp,
   playerWins: __PlayerWins[],
+  series: __SeriesState | undefined,
+  spectators: Identity[],
 };
 
 /**
@@ -56,6 +59,8 @@ export namespace GameStateTable {
       new ProductTypeElement("state", __GameState.getTypeScriptAlgebraicType()),
       new ProductTypeElement("updatedAt", AlgebraicType.createTimestampType()),
       new ProductTypeElement("playerWins", AlgebraicType.createArrayType(__PlayerWins.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("series", AlgebraicType.createOptionType(__SeriesState.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("spectators", AlgebraicType.createArrayType(AlgebraicType.createIdentityType())),
     ]);
   }
 
@@ -33,6 +33,7 @@ import {
   type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 import { SettingsState as __SettingsState } from "./settings_state_type";
+import { ReadyCheckState as __ReadyCheckState } from "./ready_check_state_type";
 import { CountdownState as __CountdownState } from "./countdown_state_type";
 import { PlayingState as __PlayingState } from "./playing_state_type";
 
@@ -42,6 +43,7 @@ export namespace GameState {
   // One type is generated per variant and will be used in the `value` field of
   // the tagged union.
   export type Settings = { tag: "Settings", value: __SettingsState };
+  export type ReadyCheck = { tag: "ReadyCheck", value: __ReadyCheckState };
   export type Countdown = { tag: "Countdown", value: __CountdownState };
   export type Playing = { tag: "Playing", value: __PlayingState };
 
@@ -52,12 +54,14 @@ export namespace GameState {
   // assert!(foo.value === 42);
   // ```
   export const Settings = (value: __SettingsState): GameState => ({ tag: "Settings", value });
+  export const ReadyCheck = (value: __ReadyCheckState): GameState => ({ tag: "ReadyCheck", value });
   export const Countdown = (value: __CountdownState): GameState => ({ tag: "Countdown", value });
   export const Playing = (value: __PlayingState): GameState => ({ tag: "Playing", value });
 
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createSumType([
       new SumTypeVariant("Settings", __SettingsState.getTypeScriptAlgebraicType()),
+      new SumTypeVariant("ReadyCheck", __ReadyCheckState.getTypeScriptAlgebraicType()),
       new SumTypeVariant("Countdown", __CountdownState.getTypeScriptAlgebraicType()),
       new SumTypeVariant("Playing", __PlayingState.getTypeScriptAlgebraicType()),
     ]);
@@ -74,7 +78,7 @@ export namespace GameState {
 }
 
 // The tagged union or sum type for the algebraic type `GameState`.
-export type GameState = GameState.Settings | GameState.Countdown | GameState.Playing;
+export type GameState = GameState.Settings | GameState.ReadyCheck | GameState.Countdown | GameState.Playing;
 
 export default GameState;
 
@@ -38,6 +38,7 @@ export type Game = {
   createdAt: Timestamp,
   updatedAt: Timestamp,
   playerIdentities: Identity[],
+  ranked: boolean,
 };
 
 /**
@@ -55,6 +56,7 @@ export namespace Game {
       new ProductTypeElement("createdAt", AlgebraicType.createTimestampType()),
       new ProductTypeElement("updatedAt", AlgebraicType.createTimestampType()),
       new ProductTypeElement("playerIdentities", AlgebraicType.createArrayType(AlgebraicType.createIdentityType())),
+      new ProductTypeElement("ranked", AlgebraicType.createBoolType()),
     ]);
   }
 
@@ -34,64 +34,218 @@ import {
 } from "@clockworklabs/spacetimedb-sdk";
 
 // Import and reexport all reducer arg types
+import { AddAdmin } from "./add_admin_reducer.ts";
+export { AddAdmin };
+import { AddBlockedWord } from "./add_blocked_word_reducer.ts";
+export { AddBlockedWord };
 import { AddPlayerToGame } from "./add_player_to_game_reducer.ts";
 export { AddPlayerToGame };
+import { ApplySettingsPreset } from "./apply_settings_preset_reducer.ts";
+export { ApplySettingsPreset };
+import { CancelCountdown } from "./cancel_countdown_reducer.ts";
+export { CancelCountdown };
+import { CleanUpIdleGames } from "./clean_up_idle_games_reducer.ts";
+export { CleanUpIdleGames };
+import { CleanUpOldChatMessages } from "./clean_up_old_chat_messages_reducer.ts";
+export { CleanUpOldChatMessages };
+import { ConfirmReady } from "./confirm_ready_reducer.ts";
+export { ConfirmReady };
 import { CreateGame } from "./create_game_reducer.ts";
 export { CreateGame };
+import { CreateGameFromPreset } from "./create_game_from_preset_reducer.ts";
+export { CreateGameFromPreset };
+import { CreateTournament } from "./create_tournament_reducer.ts";
+export { CreateTournament };
 import { DeleteGame } from "./delete_game_reducer.ts";
 export { DeleteGame };
+import { DeleteSettingsPreset } from "./delete_settings_preset_reducer.ts";
+export { DeleteSettingsPreset };
 import { GameCountdown } from "./game_countdown_reducer.ts";
 export { GameCountdown };
 import { IdentityConnected } from "./identity_connected_reducer.ts";
 export { IdentityConnected };
 import { IdentityDisconnected } from "./identity_disconnected_reducer.ts";
 export { IdentityDisconnected };
+import { JoinQuickPlayQueue } from "./join_quick_play_queue_reducer.ts";
+export { JoinQuickPlayQueue };
+import { JoinRankedQueue } from "./join_ranked_queue_reducer.ts";
+export { JoinRankedQueue };
+import { LeaveQuickPlayQueue } from "./leave_quick_play_queue_reducer.ts";
+export { LeaveQuickPlayQueue };
+import { LeaveRankedQueue } from "./leave_ranked_queue_reducer.ts";
+export { LeaveRankedQueue };
+import { MatchClockTimeout } from "./match_clock_timeout_reducer.ts";
+export { MatchClockTimeout };
+import { QuickPlayMatchmaking } from "./quick_play_matchmaking_reducer.ts";
+export { QuickPlayMatchmaking };
+import { RankedMatchmaking } from "./ranked_matchmaking_reducer.ts";
+export { RankedMatchmaking };
+import { React } from "./react_reducer.ts";
+export { React };
+import { ReadyCheckTimeout } from "./ready_check_timeout_reducer.ts";
+export { ReadyCheckTimeout };
+import { RegisterForTournament } from "./register_for_tournament_reducer.ts";
+export { RegisterForTournament };
 import { RegisterPlayer } from "./register_player_reducer.ts";
 export { RegisterPlayer };
+import { RemoveAdmin } from "./remove_admin_reducer.ts";
+export { RemoveAdmin };
+import { RemoveBlockedWord } from "./remove_blocked_word_reducer.ts";
+export { RemoveBlockedWord };
 import { RemovePlayer } from "./remove_player_reducer.ts";
 export { RemovePlayer };
 import { RestartGame } from "./restart_game_reducer.ts";
 export { RestartGame };
+import { SaveSettingsPreset } from "./save_settings_preset_reducer.ts";
+export { SaveSettingsPreset };
+import { SeedTournament } from "./seed_tournament_reducer.ts";
+export { SeedTournament };
+import { SendChatMessage } from "./send_chat_message_reducer.ts";
+export { SendChatMessage };
+import { SetChatRetention } from "./set_chat_retention_reducer.ts";
+export { SetChatRetention };
+import { SetIdleGamePeriod } from "./set_idle_game_period_reducer.ts";
+export { SetIdleGamePeriod };
+import { SetPlayerHandicap } from "./set_player_handicap_reducer.ts";
+export { SetPlayerHandicap };
+import { SetUsernamePolicy } from "./set_username_policy_reducer.ts";
+export { SetUsernamePolicy };
 import { StartGame } from "./start_game_reducer.ts";
 export { StartGame };
+import { StartNextSeriesGame } from "./start_next_series_game_reducer.ts";
+export { StartNextSeriesGame };
+import { StartTournament } from "./start_tournament_reducer.ts";
+export { StartTournament };
 import { SubmitWord } from "./submit_word_reducer.ts";
 export { SubmitWord };
 import { TurnTimeout } from "./turn_timeout_reducer.ts";
 export { TurnTimeout };
 import { UpdateBonusLetterWordCount } from "./update_bonus_letter_word_count_reducer.ts";
 export { UpdateBonusLetterWordCount };
+import { UpdateChatBlocksTrigram } from "./update_chat_blocks_trigram_reducer.ts";
+export { UpdateChatBlocksTrigram };
+import { UpdateChessClock } from "./update_chess_clock_reducer.ts";
+export { UpdateChessClock };
+import { UpdateContentFilter } from "./update_content_filter_reducer.ts";
+export { UpdateContentFilter };
+import { UpdateCountdownSeconds } from "./update_countdown_seconds_reducer.ts";
+export { UpdateCountdownSeconds };
 import { UpdateCurrentWord } from "./update_current_word_reducer.ts";
 export { UpdateCurrentWord };
+import { UpdateLimitTiebreak } from "./update_limit_tiebreak_reducer.ts";
+export { UpdateLimitTiebreak };
+import { UpdateMatchTimeLimit } from "./update_match_time_limit_reducer.ts";
+export { UpdateMatchTimeLimit };
+import { UpdateMaxPlayers } from "./update_max_players_reducer.ts";
+export { UpdateMaxPlayers };
+import { UpdateMaxRounds } from "./update_max_rounds_reducer.ts";
+export { UpdateMaxRounds };
+import { UpdateOvertimeEnabled } from "./update_overtime_enabled_reducer.ts";
+export { UpdateOvertimeEnabled };
+import { UpdatePowerUpsEnabled } from "./update_power_ups_enabled_reducer.ts";
+export { UpdatePowerUpsEnabled };
+import { UpdateReadyCheck } from "./update_ready_check_reducer.ts";
+export { UpdateReadyCheck };
+import { UpdateSeriesBestOf } from "./update_series_best_of_reducer.ts";
+export { UpdateSeriesBestOf };
 import { UpdateStartingLives } from "./update_starting_lives_reducer.ts";
 export { UpdateStartingLives };
 import { UpdateTurnLogicMode } from "./update_turn_logic_mode_reducer.ts";
 export { UpdateTurnLogicMode };
 import { UpdateTurnTimeout } from "./update_turn_timeout_reducer.ts";
 export { UpdateTurnTimeout };
+import { UpdateVisibility } from "./update_visibility_reducer.ts";
+export { UpdateVisibility };
 import { UpdateWinCondition } from "./update_win_condition_reducer.ts";
 export { UpdateWinCondition };
+import { UpdateWordTurnEffects } from "./update_word_turn_effects_reducer.ts";
+export { UpdateWordTurnEffects };
+import { UsePowerUp } from "./use_power_up_reducer.ts";
+export { UsePowerUp };
+import { VoteRematch } from "./vote_rematch_reducer.ts";
+export { VoteRematch };
+import { WithdrawFromTournament } from "./withdraw_from_tournament_reducer.ts";
+export { WithdrawFromTournament };
 
 // Import and reexport all table handle types
+import { AdminTableHandle } from "./admin_table.ts";
+export { AdminTableHandle };
+import { BlockedWordTableHandle } from "./blocked_word_table.ts";
+export { BlockedWordTableHandle };
+import { ChatCleanupScheduleTableHandle } from "./chat_cleanup_schedule_table.ts";
+export { ChatCleanupScheduleTableHandle };
+import { ChatMessageTableHandle } from "./chat_message_table.ts";
+export { ChatMessageTableHandle };
+import { ChatRateLimitTableHandle } from "./chat_rate_limit_table.ts";
+export { ChatRateLimitTableHandle };
 import { GameTableHandle } from "./game_table.ts";
 export { GameTableHandle };
+import { GameCleanupScheduleTableHandle } from "./game_cleanup_schedule_table.ts";
+export { GameCleanupScheduleTableHandle };
 import { GameCountdownScheduleTableHandle } from "./game_countdown_schedule_table.ts";
 export { GameCountdownScheduleTableHandle };
 import { GameStateTableHandle } from "./game_state_table.ts";
 export { GameStateTableHandle };
+import { LeaderboardTableHandle } from "./leaderboard_table.ts";
+export { LeaderboardTableHandle };
+import { LobbySummaryTableHandle } from "./lobby_summary_table.ts";
+export { LobbySummaryTableHandle };
+import { MatchClockScheduleTableHandle } from "./match_clock_schedule_table.ts";
+export { MatchClockScheduleTableHandle };
 import { PlayerInfoTableHandle } from "./player_info_table.ts";
 export { PlayerInfoTableHandle };
+import { QuickPlayQueueTableHandle } from "./quick_play_queue_table.ts";
+export { QuickPlayQueueTableHandle };
+import { QuickPlayScheduleTableHandle } from "./quick_play_schedule_table.ts";
+export { QuickPlayScheduleTableHandle };
+import { RankedMatchmakingScheduleTableHandle } from "./ranked_matchmaking_schedule_table.ts";
+export { RankedMatchmakingScheduleTableHandle };
+import { RankedQueueTableHandle } from "./ranked_queue_table.ts";
+export { RankedQueueTableHandle };
+import { ReadyCheckScheduleTableHandle } from "./ready_check_schedule_table.ts";
+export { ReadyCheckScheduleTableHandle };
+import { SeriesNextGameScheduleTableHandle } from "./series_next_game_schedule_table.ts";
+export { SeriesNextGameScheduleTableHandle };
+import { SettingsPresetTableHandle } from "./settings_preset_table.ts";
+export { SettingsPresetTableHandle };
+import { TournamentTableHandle } from "./tournament_table.ts";
+export { TournamentTableHandle };
+import { TournamentMatchTableHandle } from "./tournament_match_table.ts";
+export { TournamentMatchTableHandle };
+import { TournamentParticipantTableHandle } from "./tournament_participant_table.ts";
+export { TournamentParticipantTableHandle };
 import { TurnTimeoutScheduleTableHandle } from "./turn_timeout_schedule_table.ts";
 export { TurnTimeoutScheduleTableHandle };
+import { UsernamePolicyTableHandle } from "./username_policy_table.ts";
+export { UsernamePolicyTableHandle };
 
 // Import and reexport all types
+import { AdminTable } from "./admin_table_type.ts";
+export { AdminTable };
+import { BlockedWordTable } from "./blocked_word_table_type.ts";
+export { BlockedWordTable };
+import { ChatCleanupSchedule } from "./chat_cleanup_schedule_type.ts";
+export { ChatCleanupSchedule };
+import { ChatMessageTable } from "./chat_message_table_type.ts";
+export { ChatMessageTable };
+import { ChatRateLimitTable } from "./chat_rate_limit_table_type.ts";
+export { ChatRateLimitTable };
+import { ChessClockSettings } from "./chess_clock_settings_type.ts";
+export { ChessClockSettings };
 import { ClassicTurnLogic } from "./classic_turn_logic_type.ts";
 export { ClassicTurnLogic };
+import { ClockExpiry } from "./clock_expiry_type.ts";
+export { ClockExpiry };
 import { CountdownState } from "./countdown_state_type.ts";
 export { CountdownState };
+import { Emote } from "./emote_type.ts";
+export { Emote };
 import { FreeLetterAwardEvent } from "./free_letter_award_event_type.ts";
 export { FreeLetterAwardEvent };
 import { Game } from "./game_type.ts";
 export { Game };
+import { GameCleanupSchedule } from "./game_cleanup_schedule_type.ts";
+export { GameCleanupSchedule };
 import { GameCountdownSchedule } from "./game_countdown_schedule_type.ts";
 export { GameCountdownSchedule };
 import { GameResult } from "./game_result_type.ts";
@@ -104,35 +258,142 @@ import { GameStateTable } from "./game_state_table_type.ts";
 export { GameStateTable };
 import { InvalidGuessEvent } from "./invalid_guess_event_type.ts";
 export { InvalidGuessEvent };
+import { LeaderboardTable } from "./leaderboard_table_type.ts";
+export { LeaderboardTable };
+import { LobbyPhase } from "./lobby_phase_type.ts";
+export { LobbyPhase };
+import { LobbySummaryTable } from "./lobby_summary_table_type.ts";
+export { LobbySummaryTable };
+import { LobbyVisibility } from "./lobby_visibility_type.ts";
+export { LobbyVisibility };
+import { MatchClockSchedule } from "./match_clock_schedule_type.ts";
+export { MatchClockSchedule };
 import { PastGuess } from "./past_guess_type.ts";
 export { PastGuess };
 import { PlayerGameData } from "./player_game_data_type.ts";
 export { PlayerGameData };
+import { PlayerHandicap } from "./player_handicap_type.ts";
+export { PlayerHandicap };
 import { PlayerInfoTable } from "./player_info_table_type.ts";
 export { PlayerInfoTable };
+import { PlayerPlacement } from "./player_placement_type.ts";
+export { PlayerPlacement };
 import { PlayerWinConditionData } from "./player_win_condition_data_type.ts";
 export { PlayerWinConditionData };
 import { PlayerWins } from "./player_wins_type.ts";
 export { PlayerWins };
 import { PlayingState } from "./playing_state_type.ts";
 export { PlayingState };
+import { PointsGoal } from "./points_goal_type.ts";
+export { PointsGoal };
+import { PowerUp } from "./power_up_type.ts";
+export { PowerUp };
+import { PowerUpUsedEvent } from "./power_up_used_event_type.ts";
+export { PowerUpUsedEvent };
+import { QuickPlayQueueTable } from "./quick_play_queue_table_type.ts";
+export { QuickPlayQueueTable };
+import { QuickPlaySchedule } from "./quick_play_schedule_type.ts";
+export { QuickPlaySchedule };
+import { RankedMatchmakingSchedule } from "./ranked_matchmaking_schedule_type.ts";
+export { RankedMatchmakingSchedule };
+import { RankedQueueTable } from "./ranked_queue_table_type.ts";
+export { RankedQueueTable };
+import { ReactedGuess } from "./reacted_guess_type.ts";
+export { ReactedGuess };
+import { Reaction } from "./reaction_type.ts";
+export { Reaction };
+import { ReactionTarget } from "./reaction_target_type.ts";
+export { ReactionTarget };
+import { ReadyCheckSchedule } from "./ready_check_schedule_type.ts";
+export { ReadyCheckSchedule };
+import { ReadyCheckState } from "./ready_check_state_type.ts";
+export { ReadyCheckState };
+import { SeriesNextGameSchedule } from "./series_next_game_schedule_type.ts";
+export { SeriesNextGameSchedule };
+import { SeriesState } from "./series_state_type.ts";
+export { SeriesState };
+import { SettingsPresetTable } from "./settings_preset_table_type.ts";
+export { SettingsPresetTable };
 import { SettingsState } from "./settings_state_type.ts";
 export { SettingsState };
 import { SimultaneousTurnLogic } from "./simultaneous_turn_logic_type.ts";
 export { SimultaneousTurnLogic };
+import { TiebreakRule } from "./tiebreak_rule_type.ts";
+export { TiebreakRule };
+import { TournamentFormat } from "./tournament_format_type.ts";
+export { TournamentFormat };
+import { TournamentMatchTable } from "./tournament_match_table_type.ts";
+export { TournamentMatchTable };
+import { TournamentParticipantTable } from "./tournament_participant_table_type.ts";
+export { TournamentParticipantTable };
+import { TournamentStatus } from "./tournament_status_type.ts";
+export { TournamentStatus };
+import { TournamentTable } from "./tournament_table_type.ts";
+export { TournamentTable };
 import { TrigramExample } from "./trigram_example_type.ts";
 export { TrigramExample };
+import { TurnDirection } from "./turn_direction_type.ts";
+export { TurnDirection };
+import { TurnEffect } from "./turn_effect_type.ts";
+export { TurnEffect };
 import { TurnLogic } from "./turn_logic_type.ts";
 export { TurnLogic };
 import { TurnLogicMode } from "./turn_logic_mode_type.ts";
 export { TurnLogicMode };
 import { TurnTimeoutSchedule } from "./turn_timeout_schedule_type.ts";
 export { TurnTimeoutSchedule };
+import { UsernamePolicy } from "./username_policy_type.ts";
+export { UsernamePolicy };
 import { WinCondition } from "./win_condition_type.ts";
 export { WinCondition };
 
 const REMOTE_MODULE = {
   tables: {
+    admin: {
+      tableName: "admin",
+      rowType: AdminTable.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: AdminTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    blocked_word: {
+      tableName: "blocked_word",
+      rowType: BlockedWordTable.getTypeScriptAlgebraicType(),
+      primaryKey: "word",
+      primaryKeyInfo: {
+        colName: "word",
+        colType: BlockedWordTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    chat_cleanup_schedule: {
+      tableName: "chat_cleanup_schedule",
+      rowType: ChatCleanupSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: ChatCleanupSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    chat_message: {
+      tableName: "chat_message",
+      rowType: ChatMessageTable.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: ChatMessageTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    chat_rate_limit: {
+      tableName: "chat_rate_limit",
+      rowType: ChatRateLimitTable.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: ChatRateLimitTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     game: {
       tableName: "game",
       rowType: Game.getTypeScriptAlgebraicType(),
@@ -142,6 +403,15 @@ const REMOTE_MODULE = {
         colType: Game.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    game_cleanup_schedule: {
+      tableName: "game_cleanup_schedule",
+      rowType: GameCleanupSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: GameCleanupSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     game_countdown_schedule: {
       tableName: "game_countdown_schedule",
       rowType: GameCountdownSchedule.getTypeScriptAlgebraicType(),
@@ -160,6 +430,33 @@ const REMOTE_MODULE = {
         colType: GameStateTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    leaderboard: {
+      tableName: "leaderboard",
+      rowType: LeaderboardTable.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: LeaderboardTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    lobby_summary: {
+      tableName: "lobby_summary",
+      rowType: LobbySummaryTable.getTypeScriptAlgebraicType(),
+      primaryKey: "gameId",
+      primaryKeyInfo: {
+        colName: "gameId",
+        colType: LobbySummaryTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    match_clock_schedule: {
+      tableName: "match_clock_schedule",
+      rowType: MatchClockSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: MatchClockSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     player_info: {
       tableName: "player_info",
       rowType: PlayerInfoTable.getTypeScriptAlgebraicType(),
@@ -169,6 +466,96 @@ const REMOTE_MODULE = {
         colType: PlayerInfoTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    quick_play_queue: {
+      tableName: "quick_play_queue",
+      rowType: QuickPlayQueueTable.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: QuickPlayQueueTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    quick_play_schedule: {
+      tableName: "quick_play_schedule",
+      rowType: QuickPlaySchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: QuickPlaySchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    ranked_matchmaking_schedule: {
+      tableName: "ranked_matchmaking_schedule",
+      rowType: RankedMatchmakingSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: RankedMatchmakingSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    ranked_queue: {
+      tableName: "ranked_queue",
+      rowType: RankedQueueTable.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: RankedQueueTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    ready_check_schedule: {
+      tableName: "ready_check_schedule",
+      rowType: ReadyCheckSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: ReadyCheckSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    series_next_game_schedule: {
+      tableName: "series_next_game_schedule",
+      rowType: SeriesNextGameSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: SeriesNextGameSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    settings_preset: {
+      tableName: "settings_preset",
+      rowType: SettingsPresetTable.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: SettingsPresetTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    tournament: {
+      tableName: "tournament",
+      rowType: TournamentTable.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: TournamentTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    tournament_match: {
+      tableName: "tournament_match",
+      rowType: TournamentMatchTable.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: TournamentMatchTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    tournament_participant: {
+      tableName: "tournament_participant",
+      rowType: TournamentParticipantTable.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: TournamentParticipantTable.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     turn_timeout_schedule: {
       tableName: "turn_timeout_schedule",
       rowType: TurnTimeoutSchedule.getTypeScriptAlgebraicType(),
@@ -178,20 +565,69 @@ const REMOTE_MODULE = {
         colType: TurnTimeoutSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    username_policy: {
+      tableName: "username_policy",
+      rowType: UsernamePolicy.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: UsernamePolicy.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
   },
   reducers: {
+    add_admin: {
+      reducerName: "add_admin",
+      argsType: AddAdmin.getTypeScriptAlgebraicType(),
+    },
+    add_blocked_word: {
+      reducerName: "add_blocked_word",
+      argsType: AddBlockedWord.getTypeScriptAlgebraicType(),
+    },
     add_player_to_game: {
       reducerName: "add_player_to_game",
       argsType: AddPlayerToGame.getTypeScriptAlgebraicType(),
     },
+    apply_settings_preset: {
+      reducerName: "apply_settings_preset",
+      argsType: ApplySettingsPreset.getTypeScriptAlgebraicType(),
+    },
+    cancel_countdown: {
+      reducerName: "cancel_countdown",
+      argsType: CancelCountdown.getTypeScriptAlgebraicType(),
+    },
+    clean_up_idle_games: {
+      reducerName: "clean_up_idle_games",
+      argsType: CleanUpIdleGames.getTypeScriptAlgebraicType(),
+    },
+    clean_up_old_chat_messages: {
+      reducerName: "clean_up_old_chat_messages",
+      argsType: CleanUpOldChatMessages.getTypeScriptAlgebraicType(),
+    },
+    confirm_ready: {
+      reducerName: "confirm_ready",
+      argsType: ConfirmReady.getTypeScriptAlgebraicType(),
+    },
     create_game: {
       reducerName: "create_game",
       argsType: CreateGame.getTypeScriptAlgebraicType(),
     },
+    create_game_from_preset: {
+      reducerName: "create_game_from_preset",
+      argsType: CreateGameFromPreset.getTypeScriptAlgebraicType(),
+    },
+    create_tournament: {
+      reducerName: "create_tournament",
+      argsType: CreateTournament.getTypeScriptAlgebraicType(),
+    },
     delete_game: {
       reducerName: "delete_game",
       argsType: DeleteGame.getTypeScriptAlgebraicType(),
     },
+    delete_settings_preset: {
+      reducerName: "delete_settings_preset",
+      argsType: DeleteSettingsPreset.getTypeScriptAlgebraicType(),
+    },
     game_countdown: {
       reducerName: "game_countdown",
       argsType: GameCountdown.getTypeScriptAlgebraicType(),
@@ -204,10 +640,58 @@ const REMOTE_MODULE = {
       reducerName: "identity_disconnected",
       argsType: IdentityDisconnected.getTypeScriptAlgebraicType(),
     },
+    join_quick_play_queue: {
+      reducerName: "join_quick_play_queue",
+      argsType: JoinQuickPlayQueue.getTypeScriptAlgebraicType(),
+    },
+    join_ranked_queue: {
+      reducerName: "join_ranked_queue",
+      argsType: JoinRankedQueue.getTypeScriptAlgebraicType(),
+    },
+    leave_quick_play_queue: {
+      reducerName: "leave_quick_play_queue",
+      argsType: LeaveQuickPlayQueue.getTypeScriptAlgebraicType(),
+    },
+    leave_ranked_queue: {
+      reducerName: "leave_ranked_queue",
+      argsType: LeaveRankedQueue.getTypeScriptAlgebraicType(),
+    },
+    match_clock_timeout: {
+      reducerName: "match_clock_timeout",
+      argsType: MatchClockTimeout.getTypeScriptAlgebraicType(),
+    },
+    quick_play_matchmaking: {
+      reducerName: "quick_play_matchmaking",
+      argsType: QuickPlayMatchmaking.getTypeScriptAlgebraicType(),
+    },
+    ranked_matchmaking: {
+      reducerName: "ranked_matchmaking",
+      argsType: RankedMatchmaking.getTypeScriptAlgebraicType(),
+    },
+    react: {
+      reducerName: "react",
+      argsType: React.getTypeScriptAlgebraicType(),
+    },
+    ready_check_timeout: {
+      reducerName: "ready_check_timeout",
+      argsType: ReadyCheckTimeout.getTypeScriptAlgebraicType(),
+    },
+    register_for_tournament: {
+      reducerName: "register_for_tournament",
+      argsType: RegisterForTournament.getTypeScriptAlgebraicType(),
+    },
     register_player: {
       reducerName: "register_player",
       argsType: RegisterPlayer.getTypeScriptAlgebraicType(),
     },
+    remove_admin: {
+      reducerName: "remove_admin",
+      argsType: RemoveAdmin.getTypeScriptAlgebraicType(),
+    },
+    remove_blocked_word: {
+      reducerName: "remove_blocked_word",
+      argsType: RemoveBlockedWord.getTypeScriptAlgebraicType(),
+    },
     remove_player: {
       reducerName: "remove_player",
       argsType: RemovePlayer.getTypeScriptAlgebraicType(),
@@ -216,10 +700,46 @@ const REMOTE_MODULE = {
       reducerName: "restart_game",
       argsType: RestartGame.getTypeScriptAlgebraicType(),
     },
+    save_settings_preset: {
+      reducerName: "save_settings_preset",
+      argsType: SaveSettingsPreset.getTypeScriptAlgebraicType(),
+    },
+    seed_tournament: {
+      reducerName: "seed_tournament",
+      argsType: SeedTournament.getTypeScriptAlgebraicType(),
+    },
+    send_chat_message: {
+      reducerName: "send_chat_message",
+      argsType: SendChatMessage.getTypeScriptAlgebraicType(),
+    },
+    set_chat_retention: {
+      reducerName: "set_chat_retention",
+      argsType: SetChatRetention.getTypeScriptAlgebraicType(),
+    },
+    set_idle_game_period: {
+      reducerName: "set_idle_game_period",
+      argsType: SetIdleGamePeriod.getTypeScriptAlgebraicType(),
+    },
+    set_player_handicap: {
+      reducerName: "set_player_handicap",
+      argsType: SetPlayerHandicap.getTypeScriptAlgebraicType(),
+    },
+    set_username_policy: {
+      reducerName: "set_username_policy",
+      argsType: SetUsernamePolicy.getTypeScriptAlgebraicType(),
+    },
     start_game: {
       reducerName: "start_game",
       argsType: StartGame.getTypeScriptAlgebraicType(),
     },
+    start_next_series_game: {
+      reducerName: "start_next_series_game",
+      argsType: StartNextSeriesGame.getTypeScriptAlgebraicType(),
+    },
+    start_tournament: {
+      reducerName: "start_tournament",
+      argsType: StartTournament.getTypeScriptAlgebraicType(),
+    },
     submit_word: {
       reducerName: "submit_word",
       argsType: SubmitWord.getTypeScriptAlgebraicType(),
@@ -232,10 +752,58 @@ const REMOTE_MODULE = {
       reducerName: "update_bonus_letter_word_count",
       argsType: UpdateBonusLetterWordCount.getTypeScriptAlgebraicType(),
     },
+    update_chat_blocks_trigram: {
+      reducerName: "update_chat_blocks_trigram",
+      argsType: UpdateChatBlocksTrigram.getTypeScriptAlgebraicType(),
+    },
+    update_chess_clock: {
+      reducerName: "update_chess_clock",
+      argsType: UpdateChessClock.getTypeScriptAlgebraicType(),
+    },
+    update_content_filter: {
+      reducerName: "update_content_filter",
+      argsType: UpdateContentFilter.getTypeScriptAlgebraicType(),
+    },
+    update_countdown_seconds: {
+      reducerName: "update_countdown_seconds",
+      argsType: UpdateCountdownSeconds.getTypeScriptAlgebraicType(),
+    },
     update_current_word: {
       reducerName: "update_current_word",
       argsType: UpdateCurrentWord.getTypeScriptAlgebraicType(),
     },
+    update_limit_tiebreak: {
+      reducerName: "update_limit_tiebreak",
+      argsType: UpdateLimitTiebreak.getTypeScriptAlgebraicType(),
+    },
+    update_match_time_limit: {
+      reducerName: "update_match_time_limit",
+      argsType: UpdateMatchTimeLimit.getTypeScriptAlgebraicType(),
+    },
+    update_max_players: {
+      reducerName: "update_max_players",
+      argsType: UpdateMaxPlayers.getTypeScriptAlgebraicType(),
+    },
+    update_max_rounds: {
+      reducerName: "update_max_rounds",
+      argsType: UpdateMaxRounds.getTypeScriptAlgebraicType(),
+    },
+    update_overtime_enabled: {
+      reducerName: "update_overtime_enabled",
+      argsType: UpdateOvertimeEnabled.getTypeScriptAlgebraicType(),
+    },
+    update_power_ups_enabled: {
+      reducerName: "update_power_ups_enabled",
+      argsType: UpdatePowerUpsEnabled.getTypeScriptAlgebraicType(),
+    },
+    update_ready_check: {
+      reducerName: "update_ready_check",
+      argsType: UpdateReadyCheck.getTypeScriptAlgebraicType(),
+    },
+    update_series_best_of: {
+      reducerName: "update_series_best_of",
+      argsType: UpdateSeriesBestOf.getTypeScriptAlgebraicType(),
+    },
     update_starting_lives: {
       reducerName: "update_starting_lives",
       argsType: UpdateStartingLives.getTypeScriptAlgebraicType(),
@@ -248,10 +816,30 @@ const REMOTE_MODULE = {
       reducerName: "update_turn_timeout",
       argsType: UpdateTurnTimeout.getTypeScriptAlgebraicType(),
     },
+    update_visibility: {
+      reducerName: "update_visibility",
+      argsType: UpdateVisibility.getTypeScriptAlgebraicType(),
+    },
     update_win_condition: {
       reducerName: "update_win_condition",
       argsType: UpdateWinCondition.getTypeScriptAlgebraicType(),
     },
+    update_word_turn_effects: {
+      reducerName: "update_word_turn_effects",
+      argsType: UpdateWordTurnEffects.getTypeScriptAlgebraicType(),
+    },
+    use_power_up: {
+      reducerName: "use_power_up",
+      argsType: UsePowerUp.getTypeScriptAlgebraicType(),
+    },
+    vote_rematch: {
+      reducerName: "vote_rematch",
+      argsType: VoteRematch.getTypeScriptAlgebraicType(),
+    },
+    withdraw_from_tournament: {
+      reducerName: "withdraw_from_tournament",
+      argsType: WithdrawFromTournament.getTypeScriptAlgebraicType(),
+    },
   },
   versionInfo: {
     cliVersion: "1.3.0",
@@ -282,29 +870,109 @@ const REMOTE_MODULE = {
 
 // A type representing all the possible variants of a reducer.
 export type Reducer = never
+| { name: "AddAdmin", args: AddAdmin }
+| { name: "AddBlockedWord", args: AddBlockedWord }
 | { name: "AddPlayerToGame", args: AddPlayerToGame }
+| { name: "ApplySettingsPreset", args: ApplySettingsPreset }
+| { name: "CancelCountdown", args: CancelCountdown }
+| { name: "CleanUpIdleGames", args: CleanUpIdleGames }
+| { name: "CleanUpOldChatMessages", args: CleanUpOldChatMessages }
+| { name: "ConfirmReady", args: ConfirmReady }
 | { name: "CreateGame", args: CreateGame }
+| { name: "CreateGameFromPreset", args: CreateGameFromPreset }
+| { name: "CreateTournament", args: CreateTournament }
 | { name: "DeleteGame", args: DeleteGame }
+| { name: "DeleteSettingsPreset", args: DeleteSettingsPreset }
 | { name: "GameCountdown", args: GameCountdown }
 | { name: "IdentityConnected", args: IdentityConnected }
 | { name: "IdentityDisconnected", args: IdentityDisconnected }
+| { name: "JoinQuickPlayQueue", args: JoinQuickPlayQueue }
+| { name: "JoinRankedQueue", args: JoinRankedQueue }
+| { name: "LeaveQuickPlayQueue", args: LeaveQuickPlayQueue }
+| { name: "LeaveRankedQueue", args: LeaveRankedQueue }
+| { name: "MatchClockTimeout", args: MatchClockTimeout }
+| { name: "QuickPlayMatchmaking", args: QuickPlayMatchmaking }
+| { name: "RankedMatchmaking", args: RankedMatchmaking }
+| { name: "React", args: React }
+| { name: "ReadyCheckTimeout", args: ReadyCheckTimeout }
+| { name: "RegisterForTournament", args: RegisterForTournament }
 | { name: "RegisterPlayer", args: RegisterPlayer }
+| { name: "RemoveAdmin", args: RemoveAdmin }
+| { name: "RemoveBlockedWord", args: RemoveBlockedWord }
 | { name: "RemovePlayer", args: RemovePlayer }
 | { name: "RestartGame", args: RestartGame }
+| { name: "SaveSettingsPreset", args: SaveSettingsPreset }
+| { name: "SeedTournament", args: SeedTournament }
+| { name: "SendChatMessage", args: SendChatMessage }
+| { name: "SetChatRetention", args: SetChatRetention }
+| { name: "SetIdleGamePeriod", args: SetIdleGamePeriod }
+| { name: "SetPlayerHandicap", args: SetPlayerHandicap }
+| { name: "SetUsernamePolicy", args: SetUsernamePolicy }
 | { name: "StartGame", args: StartGame }
+| { name: "StartNextSeriesGame", args: StartNextSeriesGame }
+| { name: "StartTournament", args: StartTournament }
 | { name: "SubmitWord", args: SubmitWord }
 | { name: "TurnTimeout", args: TurnTimeout }
 | { name: "UpdateBonusLetterWordCount", args: UpdateBonusLetterWordCount }
+| { name: "UpdateChatBlocksTrigram", args: UpdateChatBlocksTrigram }
+| { name: "UpdateChessClock", args: UpdateChessClock }
+| { name: "UpdateContentFilter", args: UpdateContentFilter }
+| { name: "UpdateCountdownSeconds", args: UpdateCountdownSeconds }
 | { name: "UpdateCurrentWord", args: UpdateCurrentWord }
+| { name: "UpdateLimitTiebreak", args: UpdateLimitTiebreak }
+| { name: "UpdateMatchTimeLimit", args: UpdateMatchTimeLimit }
+| { name: "UpdateMaxPlayers", args: UpdateMaxPlayers }
+| { name: "UpdateMaxRounds", args: UpdateMaxRounds }
+| { name: "UpdateOvertimeEnabled", args: UpdateOvertimeEnabled }
+| { name: "UpdatePowerUpsEnabled", args: UpdatePowerUpsEnabled }
+| { name: "UpdateReadyCheck", args: UpdateReadyCheck }
+| { name: "UpdateSeriesBestOf", args: UpdateSeriesBestOf }
 | { name: "UpdateStartingLives", args: UpdateStartingLives }
 | { name: "UpdateTurnLogicMode", args: UpdateTurnLogicMode }
 | { name: "UpdateTurnTimeout", args: UpdateTurnTimeout }
+| { name: "UpdateVisibility", args: UpdateVisibility }
 | { name: "UpdateWinCondition", args: UpdateWinCondition }
+| { name: "UpdateWordTurnEffects", args: UpdateWordTurnEffects }
+| { name: "UsePowerUp", args: UsePowerUp }
+| { name: "VoteRematch", args: VoteRematch }
+| { name: "WithdrawFromTournament", args: WithdrawFromTournament }
 ;
 
 export class RemoteReducers {
   constructor(private connection: DbConnectionImpl, private setCallReducerFlags: SetReducerFlags) {}
 
+  addAdmin(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    AddAdmin.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_admin", __argsBuffer, this.setCallReducerFlags.addAdminFlags);
+  }
+
+  onAddAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("add_admin", callback);
+  }
+
+  removeOnAddAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("add_admin", callback);
+  }
+
+  addBlockedWord(word: string) {
+    const __args = { word };
+    let __writer = new BinaryWriter(1024);
+    AddBlockedWord.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_blocked_word", __argsBuffer, this.setCallReducerFlags.addBlockedWordFlags);
+  }
+
+  onAddBlockedWord(callback: (ctx: ReducerEventContext, word: string) => void) {
+    this.connection.onReducer("add_blocked_word", callback);
+  }
+
+  removeOnAddBlockedWord(callback: (ctx: ReducerEventContext, word: string) => void) {
+    this.connection.offReducer("add_blocked_word", callback);
+  }
+
   addPlayerToGame(gameId: number) {
     const __args = { gameId };
     let __writer = new BinaryWriter(1024);
@@ -321,6 +989,86 @@ export class RemoteReducers {
     this.connection.offReducer("add_player_to_game", callback);
   }
 
+  applySettingsPreset(gameId: number, presetId: number) {
+    const __args = { gameId, presetId };
+    let __writer = new BinaryWriter(1024);
+    ApplySettingsPreset.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("apply_settings_preset", __argsBuffer, this.setCallReducerFlags.applySettingsPresetFlags);
+  }
+
+  onApplySettingsPreset(callback: (ctx: ReducerEventContext, gameId: number, presetId: number) => void) {
+    this.connection.onReducer("apply_settings_preset", callback);
+  }
+
+  removeOnApplySettingsPreset(callback: (ctx: ReducerEventContext, gameId: number, presetId: number) => void) {
+    this.connection.offReducer("apply_settings_preset", callback);
+  }
+
+  cancelCountdown(gameId: number) {
+    const __args = { gameId };
+    let __writer = new BinaryWriter(1024);
+    CancelCountdown.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("cancel_countdown", __argsBuffer, this.setCallReducerFlags.cancelCountdownFlags);
+  }
+
+  onCancelCountdown(callback: (ctx: ReducerEventContext, gameId: number) => void) {
+    this.connection.onReducer("cancel_countdown", callback);
+  }
+
+  removeOnCancelCountdown(callback: (ctx: ReducerEventContext, gameId: number) => void) {
+    this.connection.offReducer("cancel_countdown", callback);
+  }
+
+  cleanUpIdleGames(arg: GameCleanupSchedule) {
+    const __args = { arg };
+    let __writer = new BinaryWriter(1024);
+    CleanUpIdleGames.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("clean_up_idle_games", __argsBuffer, this.setCallReducerFlags.cleanUpIdleGamesFlags);
+  }
+
+  onCleanUpIdleGames(callback: (ctx: ReducerEventContext, arg: GameCleanupSchedule) => void) {
+    this.connection.onReducer("clean_up_idle_games", callback);
+  }
+
+  removeOnCleanUpIdleGames(callback: (ctx: ReducerEventContext, arg: GameCleanupSchedule) => void) {
+    this.connection.offReducer("clean_up_idle_games", callback);
+  }
+
+  cleanUpOldChatMessages(arg: ChatCleanupSchedule) {
+    const __args = { arg };
+    let __writer = new BinaryWriter(1024);
+    CleanUpOldChatMessages.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("clean_up_old_chat_messages", __argsBuffer, this.setCallReducerFlags.cleanUpOldChatMessagesFlags);
+  }
+
+  onCleanUpOldChatMessages(callback: (ctx: ReducerEventContext, arg: ChatCleanupSchedule) => void) {
+    this.connection.onReducer("clean_up_old_chat_messages", callback);
+  }
+
+  removeOnCleanUpOldChatMessages(callback: (ctx: ReducerEventContext, arg: ChatCleanupSchedule) => void) {
+    this.connection.offReducer("clean_up_old_chat_messages", callback);
+  }
+
+  confirmReady(gameId: number) {
+    const __args = { gameId };
+    let __writer = new BinaryWriter(1024);
+    ConfirmReady.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("confirm_ready", __argsBuffer, this.setCallReducerFlags.confirmReadyFlags);
+  }
+
+  onConfirmReady(callback: (ctx: ReducerEventContext, gameId: number) => void) {
+    this.connection.onReducer("confirm_ready", callback);
+  }
+
+  removeOnConfirmReady(callback: (ctx: ReducerEventContext, gameId: number) => void) {
+    this.connection.offReducer("confirm_ready", callback);
+  }
+
   createGame(name: string) {
     const __args = { name };
     let __writer = new BinaryWriter(1024);
@@ -337,6 +1085,38 @@ export class RemoteReducers {
     this.connection.offReducer("create_game", callback);
   }
 
+  createGameFromPreset(name: string, presetId: number) {
+    const __args = { name, presetId };
+    let __writer = new BinaryWriter(1024);
+    CreateGameFromPreset.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_game_from_preset", __argsBuffer, this.setCallReducerFlags.createGameFromPresetFlags);
+  }
+
+  onCreateGameFromPreset(callback: (ctx: ReducerEventContext, name: string, presetId: number) => void) {
+    this.connection.onReducer("create_game_from_preset", callback);
+  }
+
+  removeOnCreateGameFromPreset(callback: (ctx: ReducerEventContext, name: string, presetId: number) => void) {
+    this.connection.offReducer("create_game_from_preset", callback);
+  }
+
+  createTournament(name: string, format: TournamentFormat, presetId: number | undefined) {
+    const __args = { name, format, presetId };
+    let __writer = new BinaryWriter(1024);
+    CreateTournament.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_tournament", __argsBuffer, this.setCallReducerFlags.createTournamentFlags);
+  }
+
+  onCreateTournament(callback: (ctx: ReducerEventContext, name: string, format: TournamentFormat, presetId: number | undefined) => void) {
+    this.connection.onReducer("create_tournament", callback);
+  }
+
+  removeOnCreateTournament(callback: (ctx: ReducerEventContext, name: string, format: TournamentFormat, presetId: number | undefined) => void) {
+    this.connection.offReducer("create_tournament", callback);
+  }
+
   deleteGame(gameId: number) {
     const __args = { gameId };
     let __writer = new BinaryWriter(1024);
@@ -353,6 +1133,22 @@ export class RemoteReducers {
     this.connection.offReducer("delete_game", callback);
   }
 
+  deleteSettingsPreset(presetId: number) {
+    const __args = { presetId };
+    let __writer = new BinaryWriter(1024);
+    DeleteSettingsPreset.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_settings_preset", __argsBuffer, this.setCallReducerFlags.deleteSettingsPresetFlags);
+  }
+
+  onDeleteSettingsPreset(callback: (ctx: ReducerEventContext, presetId: number) => void) {
+    this.connection.onReducer("delete_settings_preset", callback);
+  }
+
+  removeOnDeleteSettingsPreset(callback: (ctx: ReducerEventContext, presetId: number) => void) {
+    this.connection.offReducer("delete_settings_preset", callback);
+  }
+
   gameCountdown(arg: GameCountdownSchedule) {
     const __args = { arg };
     let __writer = new BinaryWriter(1024);
@@ -385,54 +1181,346 @@ export class RemoteReducers {
     this.connection.offReducer("identity_disconnected", callback);
   }
 
-  registerPlayer(username: string) {
-    const __args = { username };
+  joinQuickPlayQueue(preferredMode: TurnLogicMode | undefined) {
+    const __args = { preferredMode };
     let __writer = new BinaryWriter(1024);
-    RegisterPlayer.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    JoinQuickPlayQueue.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("register_player", __argsBuffer, this.setCallReducerFlags.registerPlayerFlags);
+    this.connection.callReducer("join_quick_play_queue", __argsBuffer, this.setCallReducerFlags.joinQuickPlayQueueFlags);
   }
 
-  onRegisterPlayer(callback: (ctx: ReducerEventContext, username: string) => void) {
-    this.connection.onReducer("register_player", callback);
+  onJoinQuickPlayQueue(callback: (ctx: ReducerEventContext, preferredMode: TurnLogicMode | undefined) => void) {
+    this.connection.onReducer("join_quick_play_queue", callback);
   }
 
-  removeOnRegisterPlayer(callback: (ctx: ReducerEventContext, username: string) => void) {
-    this.connection.offReducer("register_player", callback);
+  removeOnJoinQuickPlayQueue(callback: (ctx: ReducerEventContext, preferredMode: TurnLogicMode | undefined) => void) {
+    this.connection.offReducer("join_quick_play_queue", callback);
   }
 
-  removePlayer(gameId: number, playerIdentity: Identity) {
-    const __args = { gameId, playerIdentity };
-    let __writer = new BinaryWriter(1024);
-    RemovePlayer.getTypeScriptAlgebraicType().serialize(__writer, __args);
-    let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("remove_player", __argsBuffer, this.setCallReducerFlags.removePlayerFlags);
+  joinRankedQueue() {
+    this.connection.callReducer("join_ranked_queue", new Uint8Array(0), this.setCallReducerFlags.joinRankedQueueFlags);
   }
 
-  onRemovePlayer(callback: (ctx: ReducerEventContext, gameId: number, playerIdentity: Identity) => void) {
-    this.connection.onReducer("remove_player", callback);
+  onJoinRankedQueue(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("join_ranked_queue", callback);
   }
 
-  removeOnRemovePlayer(callback: (ctx: ReducerEventContext, gameId: number, playerIdentity: Identity) => void) {
-    this.connection.offReducer("remove_player", callback);
+  removeOnJoinRankedQueue(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("join_ranked_queue", callback);
   }
 
-  restartGame(gameId: number) {
-    const __args = { gameId };
-    let __writer = new BinaryWriter(1024);
-    RestartGame.getTypeScriptAlgebraicType().serialize(__writer, __args);
-    let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("restart_game", __argsBuffer, this.setCallReducerFlags.restartGameFlags);
+  leaveQuickPlayQueue() {
+    this.connection.callReducer("leave_quick_play_queue", new Uint8Array(0), this.setCallReducerFlags.leaveQuickPlayQueueFlags);
   }
 
-  onRestartGame(callback: (ctx: ReducerEventContext, gameId: number) => void) {
-    this.connection.onReducer("restart_game", callback);
+  onLeaveQuickPlayQueue(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("leave_quick_play_queue", callback);
+  }
+
+  removeOnLeaveQuickPlayQueue(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("leave_quick_play_queue", callback);
+  }
+
+  leaveRankedQueue() {
+    this.connection.callReducer("leave_ranked_queue", new Uint8Array(0), this.setCallReducerFlags.leaveRankedQueueFlags);
+  }
+
+  onLeaveRankedQueue(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("leave_ranked_queue", callback);
+  }
+
+  removeOnLeaveRankedQueue(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("leave_ranked_queue", callback);
+  }
+
+  matchClockTimeout(arg: MatchClockSchedule) {
+    const __args = { arg };
+    let __writer = new BinaryWriter(1024);
+    MatchClockTimeout.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("match_clock_timeout", __argsBuffer, this.setCallReducerFlags.matchClockTimeoutFlags);
+  }
+
+  onMatchClockTimeout(callback: (ctx: ReducerEventContext, arg: MatchClockSchedule) => void) {
+    this.connection.onReducer("match_clock_timeout", callback);
+  }
+
+  removeOnMatchClockTimeout(callback: (ctx: ReducerEventContext, arg: MatchClockSchedule) => void) {
+    this.connection.offReducer("match_clock_timeout", callback);
+  }
+
+  quickPlayMatchmaking(arg: QuickPlaySchedule) {
+    const __args = { arg };
+    let __writer = new BinaryWriter(1024);
+    QuickPlayMatchmaking.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("quick_play_matchmaking", __argsBuffer, this.setCallReducerFlags.quickPlayMatchmakingFlags);
+  }
+
+  onQuickPlayMatchmaking(callback: (ctx: ReducerEventContext, arg: QuickPlaySchedule) => void) {
+    this.connection.onReducer("quick_play_matchmaking", callback);
+  }
+
+  removeOnQuickPlayMatchmaking(callback: (ctx: ReducerEventContext, arg: QuickPlaySchedule) => void) {
+    this.connection.offReducer("quick_play_matchmaking", callback);
+  }
+
+  rankedMatchmaking(arg: RankedMatchmakingSchedule) {
+    const __args = { arg };
+    let __writer = new BinaryWriter(1024);
+    RankedMatchmaking.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("ranked_matchmaking", __argsBuffer, this.setCallReducerFlags.rankedMatchmakingFlags);
+  }
+
+  onRankedMatchmaking(callback: (ctx: ReducerEventContext, arg: RankedMatchmakingSchedule) => void) {
+    this.connection.onReducer("ranked_matchmaking", callback);
+  }
+
+  removeOnRankedMatchmaking(callback: (ctx: ReducerEventContext, arg: RankedMatchmakingSchedule) => void) {
+    this.connection.offReducer("ranked_matchmaking", callback);
+  }
+
+  react(gameId: number, emote: Emote, target: ReactionTarget) {
+    const __args = { gameId, emote, target };
+    let __writer = new BinaryWriter(1024);
+    React.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("react", __argsBuffer, this.setCallReducerFlags.reactFlags);
+  }
+
+  onReact(callback: (ctx: ReducerEventContext, gameId: number, emote: Emote, target: ReactionTarget) => void) {
+    this.connection.onReducer("react", callback);
+  }
+
+  removeOnReact(callback: (ctx: ReducerEventContext, gameId: number, emote: Emote, target: ReactionTarget) => void) {
+    this.connection.offReducer("react", callback);
+  }
+
+  readyCheckTimeout(arg: ReadyCheckSchedule) {
+    const __args = { arg };
+    let __writer = new BinaryWriter(1024);
+    ReadyCheckTimeout.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("ready_check_timeout", __argsBuffer, this.setCallReducerFlags.readyCheckTimeoutFlags);
+  }
+
+  onReadyCheckTimeout(callback: (ctx: ReducerEventContext, arg: ReadyCheckSchedule) => void) {
+    this.connection.onReducer("ready_check_timeout", callback);
+  }
+
+  removeOnReadyCheckTimeout(callback: (ctx: ReducerEventContext, arg: ReadyCheckSchedule) => void) {
+    this.connection.offReducer("ready_check_timeout", callback);
+  }
+
+  registerForTournament(tournamentId: number) {
+    const __args = { tournamentId };
+    let __writer = new BinaryWriter(1024);
+    RegisterForTournament.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("register_for_tournament", __argsBuffer, this.setCallReducerFlags.registerForTournamentFlags);
+  }
+
+  onRegisterForTournament(callback: (ctx: ReducerEventContext, tournamentId: number) => void) {
+    this.connection.onReducer("register_for_tournament", callback);
+  }
+
+  removeOnRegisterForTournament(callback: (ctx: ReducerEventContext, tournamentId: number) => void) {
+    this.connection.offReducer("register_for_tournament", callback);
+  }
+
+  registerPlayer(username: string) {
+    const __args = { username };
+    let __writer = new BinaryWriter(1024);
+    RegisterPlayer.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("register_player", __argsBuffer, this.setCallReducerFlags.registerPlayerFlags);
+  }
+
+  onRegisterPlayer(callback: (ctx: ReducerEventContext, username: string) => void) {
+    this.connection.onReducer("register_player", callback);
+  }
+
+  removeOnRegisterPlayer(callback: (ctx: ReducerEventContext, username: string) => void) {
+    this.connection.offReducer("register_player", callback);
+  }
+
+  removeAdmin(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    RemoveAdmin.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_admin", __argsBuffer, this.setCallReducerFlags.removeAdminFlags);
+  }
+
+  onRemoveAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("remove_admin", callback);
+  }
+
+  removeOnRemoveAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("remove_admin", callback);
+  }
+
+  removeBlockedWord(word: string) {
+    const __args = { word };
+    let __writer = new BinaryWriter(1024);
+    RemoveBlockedWord.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_blocked_word", __argsBuffer, this.setCallReducerFlags.removeBlockedWordFlags);
+  }
+
+  onRemoveBlockedWord(callback: (ctx: ReducerEventContext, word: string) => void) {
+    this.connection.onReducer("remove_blocked_word", callback);
+  }
+
+  removeOnRemoveBlockedWord(callback: (ctx: ReducerEventContext, word: string) => void) {
+    this.connection.offReducer("remove_blocked_word", callback);
+  }
+
+  removePlayer(gameId: number, playerIdentity: Identity) {
+    const __args = { gameId, playerIdentity };
+    let __writer = new BinaryWriter(1024);
+    RemovePlayer.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_player", __argsBuffer, this.setCallReducerFlags.removePlayerFlags);
+  }
+
+  onRemovePlayer(callback: (ctx: ReducerEventContext, gameId: number, playerIdentity: Identity) => void) {
+    this.connection.onReducer("remove_player", callback);
+  }
+
+  removeOnRemovePlayer(callback: (ctx: ReducerEventContext, gameId: number, playerIdentity: Identity) => void) {
+    this.connection.offReducer("remove_player", callback);
+  }
+
+  restartGame(gameId: number) {
+    const __args = { gameId };
+    let __writer = new BinaryWriter(1024);
+    RestartGame.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("restart_game", __argsBuffer, this.setCallReducerFlags.restartGameFlags);
+  }
+
+  onRestartGame(callback: (ctx: ReducerEventContext, gameId: number) => void) {
+    this.connection.onReducer("restart_game", callback);
   }
 
   removeOnRestartGame(callback: (ctx: ReducerEventContext, gameId: number) => void) {
     this.connection.offReducer("restart_game", callback);
   }
 
+  saveSettingsPreset(gameId: number, name: string) {
+    const __args = { gameId, name };
+    let __writer = new BinaryWriter(1024);
+    SaveSettingsPreset.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("save_settings_preset", __argsBuffer, this.setCallReducerFlags.saveSettingsPresetFlags);
+  }
+
+  onSaveSettingsPreset(callback: (ctx: ReducerEventContext, gameId: number, name: string) => void) {
+    this.connection.onReducer("save_settings_preset", callback);
+  }
+
+  removeOnSaveSettingsPreset(callback: (ctx: ReducerEventContext, gameId: number, name: string) => void) {
+    this.connection.offReducer("save_settings_preset", callback);
+  }
+
+  seedTournament(tournamentId: number) {
+    const __args = { tournamentId };
+    let __writer = new BinaryWriter(1024);
+    SeedTournament.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("seed_tournament", __argsBuffer, this.setCallReducerFlags.seedTournamentFlags);
+  }
+
+  onSeedTournament(callback: (ctx: ReducerEventContext, tournamentId: number) => void) {
+    this.connection.onReducer("seed_tournament", callback);
+  }
+
+  removeOnSeedTournament(callback: (ctx: ReducerEventContext, tournamentId: number) => void) {
+    this.connection.offReducer("seed_tournament", callback);
+  }
+
+  sendChatMessage(gameId: number | undefined, text: string) {
+    const __args = { gameId, text };
+    let __writer = new BinaryWriter(1024);
+    SendChatMessage.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_chat_message", __argsBuffer, this.setCallReducerFlags.sendChatMessageFlags);
+  }
+
+  onSendChatMessage(callback: (ctx: ReducerEventContext, gameId: number | undefined, text: string) => void) {
+    this.connection.onReducer("send_chat_message", callback);
+  }
+
+  removeOnSendChatMessage(callback: (ctx: ReducerEventContext, gameId: number | undefined, text: string) => void) {
+    this.connection.offReducer("send_chat_message", callback);
+  }
+
+  setChatRetention(retentionSeconds: number) {
+    const __args = { retentionSeconds };
+    let __writer = new BinaryWriter(1024);
+    SetChatRetention.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_chat_retention", __argsBuffer, this.setCallReducerFlags.setChatRetentionFlags);
+  }
+
+  onSetChatRetention(callback: (ctx: ReducerEventContext, retentionSeconds: number) => void) {
+    this.connection.onReducer("set_chat_retention", callback);
+  }
+
+  removeOnSetChatRetention(callback: (ctx: ReducerEventContext, retentionSeconds: number) => void) {
+    this.connection.offReducer("set_chat_retention", callback);
+  }
+
+  setIdleGamePeriod(idleSeconds: number) {
+    const __args = { idleSeconds };
+    let __writer = new BinaryWriter(1024);
+    SetIdleGamePeriod.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_idle_game_period", __argsBuffer, this.setCallReducerFlags.setIdleGamePeriodFlags);
+  }
+
+  onSetIdleGamePeriod(callback: (ctx: ReducerEventContext, idleSeconds: number) => void) {
+    this.connection.onReducer("set_idle_game_period", callback);
+  }
+
+  removeOnSetIdleGamePeriod(callback: (ctx: ReducerEventContext, idleSeconds: number) => void) {
+    this.connection.offReducer("set_idle_game_period", callback);
+  }
+
+  setPlayerHandicap(gameId: number, playerIdentity: Identity, handicap: PlayerHandicap) {
+    const __args = { gameId, playerIdentity, handicap };
+    let __writer = new BinaryWriter(1024);
+    SetPlayerHandicap.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_player_handicap", __argsBuffer, this.setCallReducerFlags.setPlayerHandicapFlags);
+  }
+
+  onSetPlayerHandicap(callback: (ctx: ReducerEventContext, gameId: number, playerIdentity: Identity, handicap: PlayerHandicap) => void) {
+    this.connection.onReducer("set_player_handicap", callback);
+  }
+
+  removeOnSetPlayerHandicap(callback: (ctx: ReducerEventContext, gameId: number, playerIdentity: Identity, handicap: PlayerHandicap) => void) {
+    this.connection.offReducer("set_player_handicap", callback);
+  }
+
+  setUsernamePolicy(requireUnique: boolean, renameCooldownSeconds: number) {
+    const __args = { requireUnique, renameCooldownSeconds };
+    let __writer = new BinaryWriter(1024);
+    SetUsernamePolicy.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_username_policy", __argsBuffer, this.setCallReducerFlags.setUsernamePolicyFlags);
+  }
+
+  onSetUsernamePolicy(callback: (ctx: ReducerEventContext, requireUnique: boolean, renameCooldownSeconds: number) => void) {
+    this.connection.onReducer("set_username_policy", callback);
+  }
+
+  removeOnSetUsernamePolicy(callback: (ctx: ReducerEventContext, requireUnique: boolean, renameCooldownSeconds: number) => void) {
+    this.connection.offReducer("set_username_policy", callback);
+  }
+
   startGame(gameId: number) {
     const __args = { gameId };
     let __writer = new BinaryWriter(1024);
@@ -449,6 +1537,38 @@ export class RemoteReducers {
     this.connection.offReducer("start_game", callback);
   }
 
+  startNextSeriesGame(arg: SeriesNextGameSchedule) {
+    const __args = { arg };
+    let __writer = new BinaryWriter(1024);
+    StartNextSeriesGame.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("start_next_series_game", __argsBuffer, this.setCallReducerFlags.startNextSeriesGameFlags);
+  }
+
+  onStartNextSeriesGame(callback: (ctx: ReducerEventContext, arg: SeriesNextGameSchedule) => void) {
+    this.connection.onReducer("start_next_series_game", callback);
+  }
+
+  removeOnStartNextSeriesGame(callback: (ctx: ReducerEventContext, arg: SeriesNextGameSchedule) => void) {
+    this.connection.offReducer("start_next_series_game", callback);
+  }
+
+  startTournament(tournamentId: number) {
+    const __args = { tournamentId };
+    let __writer = new BinaryWriter(1024);
+    StartTournament.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("start_tournament", __argsBuffer, this.setCallReducerFlags.startTournamentFlags);
+  }
+
+  onStartTournament(callback: (ctx: ReducerEventContext, tournamentId: number) => void) {
+    this.connection.onReducer("start_tournament", callback);
+  }
+
+  removeOnStartTournament(callback: (ctx: ReducerEventContext, tournamentId: number) => void) {
+    this.connection.offReducer("start_tournament", callback);
+  }
+
   submitWord(gameId: number, word: string, turnNumber: number) {
     const __args = { gameId, word, turnNumber };
     let __writer = new BinaryWriter(1024);
@@ -497,6 +1617,70 @@ export class RemoteReducers {
     this.connection.offReducer("update_bonus_letter_word_count", callback);
   }
 
+  updateChatBlocksTrigram(gameId: number, chatBlocksTrigram: boolean) {
+    const __args = { gameId, chatBlocksTrigram };
+    let __writer = new BinaryWriter(1024);
+    UpdateChatBlocksTrigram.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_chat_blocks_trigram", __argsBuffer, this.setCallReducerFlags.updateChatBlocksTrigramFlags);
+  }
+
+  onUpdateChatBlocksTrigram(callback: (ctx: ReducerEventContext, gameId: number, chatBlocksTrigram: boolean) => void) {
+    this.connection.onReducer("update_chat_blocks_trigram", callback);
+  }
+
+  removeOnUpdateChatBlocksTrigram(callback: (ctx: ReducerEventContext, gameId: number, chatBlocksTrigram: boolean) => void) {
+    this.connection.offReducer("update_chat_blocks_trigram", callback);
+  }
+
+  updateChessClock(gameId: number, chessClock: ChessClockSettings | undefined) {
+    const __args = { gameId, chessClock };
+    let __writer = new BinaryWriter(1024);
+    UpdateChessClock.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_chess_clock", __argsBuffer, this.setCallReducerFlags.updateChessClockFlags);
+  }
+
+  onUpdateChessClock(callback: (ctx: ReducerEventContext, gameId: number, chessClock: ChessClockSettings | undefined) => void) {
+    this.connection.onReducer("update_chess_clock", callback);
+  }
+
+  removeOnUpdateChessClock(callback: (ctx: ReducerEventContext, gameId: number, chessClock: ChessClockSettings | undefined) => void) {
+    this.connection.offReducer("update_chess_clock", callback);
+  }
+
+  updateContentFilter(gameId: number, contentFilter: boolean) {
+    const __args = { gameId, contentFilter };
+    let __writer = new BinaryWriter(1024);
+    UpdateContentFilter.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_content_filter", __argsBuffer, this.setCallReducerFlags.updateContentFilterFlags);
+  }
+
+  onUpdateContentFilter(callback: (ctx: ReducerEventContext, gameId: number, contentFilter: boolean) => void) {
+    this.connection.onReducer("update_content_filter", callback);
+  }
+
+  removeOnUpdateContentFilter(callback: (ctx: ReducerEventContext, gameId: number, contentFilter: boolean) => void) {
+    this.connection.offReducer("update_content_filter", callback);
+  }
+
+  updateCountdownSeconds(gameId: number, seconds: number) {
+    const __args = { gameId, seconds };
+    let __writer = new BinaryWriter(1024);
+    UpdateCountdownSeconds.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_countdown_seconds", __argsBuffer, this.setCallReducerFlags.updateCountdownSecondsFlags);
+  }
+
+  onUpdateCountdownSeconds(callback: (ctx: ReducerEventContext, gameId: number, seconds: number) => void) {
+    this.connection.onReducer("update_countdown_seconds", callback);
+  }
+
+  removeOnUpdateCountdownSeconds(callback: (ctx: ReducerEventContext, gameId: number, seconds: number) => void) {
+    this.connection.offReducer("update_countdown_seconds", callback);
+  }
+
   updateCurrentWord(gameId: number, word: string) {
     const __args = { gameId, word };
     let __writer = new BinaryWriter(1024);
@@ -513,6 +1697,134 @@ export class RemoteReducers {
     this.connection.offReducer("update_current_word", callback);
   }
 
+  updateLimitTiebreak(gameId: number, tiebreak: TiebreakRule) {
+    const __args = { gameId, tiebreak };
+    let __writer = new BinaryWriter(1024);
+    UpdateLimitTiebreak.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_limit_tiebreak", __argsBuffer, this.setCallReducerFlags.updateLimitTiebreakFlags);
+  }
+
+  onUpdateLimitTiebreak(callback: (ctx: ReducerEventContext, gameId: number, tiebreak: TiebreakRule) => void) {
+    this.connection.onReducer("update_limit_tiebreak", callback);
+  }
+
+  removeOnUpdateLimitTiebreak(callback: (ctx: ReducerEventContext, gameId: number, tiebreak: TiebreakRule) => void) {
+    this.connection.offReducer("update_limit_tiebreak", callback);
+  }
+
+  updateMatchTimeLimit(gameId: number, seconds: number | undefined) {
+    const __args = { gameId, seconds };
+    let __writer = new BinaryWriter(1024);
+    UpdateMatchTimeLimit.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_match_time_limit", __argsBuffer, this.setCallReducerFlags.updateMatchTimeLimitFlags);
+  }
+
+  onUpdateMatchTimeLimit(callback: (ctx: ReducerEventContext, gameId: number, seconds: number | undefined) => void) {
+    this.connection.onReducer("update_match_time_limit", callback);
+  }
+
+  removeOnUpdateMatchTimeLimit(callback: (ctx: ReducerEventContext, gameId: number, seconds: number | undefined) => void) {
+    this.connection.offReducer("update_match_time_limit", callback);
+  }
+
+  updateMaxPlayers(gameId: number, maxPlayers: number) {
+    const __args = { gameId, maxPlayers };
+    let __writer = new BinaryWriter(1024);
+    UpdateMaxPlayers.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_max_players", __argsBuffer, this.setCallReducerFlags.updateMaxPlayersFlags);
+  }
+
+  onUpdateMaxPlayers(callback: (ctx: ReducerEventContext, gameId: number, maxPlayers: number) => void) {
+    this.connection.onReducer("update_max_players", callback);
+  }
+
+  removeOnUpdateMaxPlayers(callback: (ctx: ReducerEventContext, gameId: number, maxPlayers: number) => void) {
+    this.connection.offReducer("update_max_players", callback);
+  }
+
+  updateMaxRounds(gameId: number, maxRounds: number | undefined) {
+    const __args = { gameId, maxRounds };
+    let __writer = new BinaryWriter(1024);
+    UpdateMaxRounds.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_max_rounds", __argsBuffer, this.setCallReducerFlags.updateMaxRoundsFlags);
+  }
+
+  onUpdateMaxRounds(callback: (ctx: ReducerEventContext, gameId: number, maxRounds: number | undefined) => void) {
+    this.connection.onReducer("update_max_rounds", callback);
+  }
+
+  removeOnUpdateMaxRounds(callback: (ctx: ReducerEventContext, gameId: number, maxRounds: number | undefined) => void) {
+    this.connection.offReducer("update_max_rounds", callback);
+  }
+
+  updateOvertimeEnabled(gameId: number, enabled: boolean) {
+    const __args = { gameId, enabled };
+    let __writer = new BinaryWriter(1024);
+    UpdateOvertimeEnabled.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_overtime_enabled", __argsBuffer, this.setCallReducerFlags.updateOvertimeEnabledFlags);
+  }
+
+  onUpdateOvertimeEnabled(callback: (ctx: ReducerEventContext, gameId: number, enabled: boolean) => void) {
+    this.connection.onReducer("update_overtime_enabled", callback);
+  }
+
+  removeOnUpdateOvertimeEnabled(callback: (ctx: ReducerEventContext, gameId: number, enabled: boolean) => void) {
+    this.connection.offReducer("update_overtime_enabled", callback);
+  }
+
+  updatePowerUpsEnabled(gameId: number, enabled: boolean) {
+    const __args = { gameId, enabled };
+    let __writer = new BinaryWriter(1024);
+    UpdatePowerUpsEnabled.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_power_ups_enabled", __argsBuffer, this.setCallReducerFlags.updatePowerUpsEnabledFlags);
+  }
+
+  onUpdatePowerUpsEnabled(callback: (ctx: ReducerEventContext, gameId: number, enabled: boolean) => void) {
+    this.connection.onReducer("update_power_ups_enabled", callback);
+  }
+
+  removeOnUpdatePowerUpsEnabled(callback: (ctx: ReducerEventContext, gameId: number, enabled: boolean) => void) {
+    this.connection.offReducer("update_power_ups_enabled", callback);
+  }
+
+  updateReadyCheck(gameId: number, seconds: number | undefined) {
+    const __args = { gameId, seconds };
+    let __writer = new BinaryWriter(1024);
+    UpdateReadyCheck.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_ready_check", __argsBuffer, this.setCallReducerFlags.updateReadyCheckFlags);
+  }
+
+  onUpdateReadyCheck(callback: (ctx: ReducerEventContext, gameId: number, seconds: number | undefined) => void) {
+    this.connection.onReducer("update_ready_check", callback);
+  }
+
+  removeOnUpdateReadyCheck(callback: (ctx: ReducerEventContext, gameId: number, seconds: number | undefined) => void) {
+    this.connection.offReducer("update_ready_check", callback);
+  }
+
+  updateSeriesBestOf(gameId: number, bestOf: number | undefined) {
+    const __args = { gameId, bestOf };
+    let __writer = new BinaryWriter(1024);
+    UpdateSeriesBestOf.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_series_best_of", __argsBuffer, this.setCallReducerFlags.updateSeriesBestOfFlags);
+  }
+
+  onUpdateSeriesBestOf(callback: (ctx: ReducerEventContext, gameId: number, bestOf: number | undefined) => void) {
+    this.connection.onReducer("update_series_best_of", callback);
+  }
+
+  removeOnUpdateSeriesBestOf(callback: (ctx: ReducerEventContext, gameId: number, bestOf: number | undefined) => void) {
+    this.connection.offReducer("update_series_best_of", callback);
+  }
+
   updateStartingLives(gameId: number, startingLives: number) {
     const __args = { gameId, startingLives };
     let __writer = new BinaryWriter(1024);
@@ -561,6 +1873,22 @@ export class RemoteReducers {
     this.connection.offReducer("update_turn_timeout", callback);
   }
 
+  updateVisibility(gameId: number, visibility: LobbyVisibility) {
+    const __args = { gameId, visibility };
+    let __writer = new BinaryWriter(1024);
+    UpdateVisibility.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_visibility", __argsBuffer, this.setCallReducerFlags.updateVisibilityFlags);
+  }
+
+  onUpdateVisibility(callback: (ctx: ReducerEventContext, gameId: number, visibility: LobbyVisibility) => void) {
+    this.connection.onReducer("update_visibility", callback);
+  }
+
+  removeOnUpdateVisibility(callback: (ctx: ReducerEventContext, gameId: number, visibility: LobbyVisibility) => void) {
+    this.connection.offReducer("update_visibility", callback);
+  }
+
   updateWinCondition(gameId: number, winCondition: WinCondition) {
     const __args = { gameId, winCondition };
     let __writer = new BinaryWriter(1024);
@@ -577,34 +1905,208 @@ export class RemoteReducers {
     this.connection.offReducer("update_win_condition", callback);
   }
 
+  updateWordTurnEffects(gameId: number, enabled: boolean) {
+    const __args = { gameId, enabled };
+    let __writer = new BinaryWriter(1024);
+    UpdateWordTurnEffects.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_word_turn_effects", __argsBuffer, this.setCallReducerFlags.updateWordTurnEffectsFlags);
+  }
+
+  onUpdateWordTurnEffects(callback: (ctx: ReducerEventContext, gameId: number, enabled: boolean) => void) {
+    this.connection.onReducer("update_word_turn_effects", callback);
+  }
+
+  removeOnUpdateWordTurnEffects(callback: (ctx: ReducerEventContext, gameId: number, enabled: boolean) => void) {
+    this.connection.offReducer("update_word_turn_effects", callback);
+  }
+
+  usePowerUp(gameId: number, powerUp: PowerUp) {
+    const __args = { gameId, powerUp };
+    let __writer = new BinaryWriter(1024);
+    UsePowerUp.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("use_power_up", __argsBuffer, this.setCallReducerFlags.usePowerUpFlags);
+  }
+
+  onUsePowerUp(callback: (ctx: ReducerEventContext, gameId: number, powerUp: PowerUp) => void) {
+    this.connection.onReducer("use_power_up", callback);
+  }
+
+  removeOnUsePowerUp(callback: (ctx: ReducerEventContext, gameId: number, powerUp: PowerUp) => void) {
+    this.connection.offReducer("use_power_up", callback);
+  }
+
+  voteRematch(gameId: number) {
+    const __args = { gameId };
+    let __writer = new BinaryWriter(1024);
+    VoteRematch.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("vote_rematch", __argsBuffer, this.setCallReducerFlags.voteRematchFlags);
+  }
+
+  onVoteRematch(callback: (ctx: ReducerEventContext, gameId: number) => void) {
+    this.connection.onReducer("vote_rematch", callback);
+  }
+
+  removeOnVoteRematch(callback: (ctx: ReducerEventContext, gameId: number) => void) {
+    this.connection.offReducer("vote_rematch", callback);
+  }
+
+  withdrawFromTournament(tournamentId: number) {
+    const __args = { tournamentId };
+    let __writer = new BinaryWriter(1024);
+    WithdrawFromTournament.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("withdraw_from_tournament", __argsBuffer, this.setCallReducerFlags.withdrawFromTournamentFlags);
+  }
+
+  onWithdrawFromTournament(callback: (ctx: ReducerEventContext, tournamentId: number) => void) {
+    this.connection.onReducer("withdraw_from_tournament", callback);
+  }
+
+  removeOnWithdrawFromTournament(callback: (ctx: ReducerEventContext, tournamentId: number) => void) {
+    this.connection.offReducer("withdraw_from_tournament", callback);
+  }
+
 }
 
 export class SetReducerFlags {
+  addAdminFlags: CallReducerFlags = 'FullUpdate';
+  addAdmin(flags: CallReducerFlags) {
+    this.addAdminFlags = flags;
+  }
+
+  addBlockedWordFlags: CallReducerFlags = 'FullUpdate';
+  addBlockedWord(flags: CallReducerFlags) {
+    this.addBlockedWordFlags = flags;
+  }
+
   addPlayerToGameFlags: CallReducerFlags = 'FullUpdate';
   addPlayerToGame(flags: CallReducerFlags) {
     this.addPlayerToGameFlags = flags;
   }
 
+  applySettingsPresetFlags: CallReducerFlags = 'FullUpdate';
+  applySettingsPreset(flags: CallReducerFlags) {
+    this.applySettingsPresetFlags = flags;
+  }
+
+  cancelCountdownFlags: CallReducerFlags = 'FullUpdate';
+  cancelCountdown(flags: CallReducerFlags) {
+    this.cancelCountdownFlags = flags;
+  }
+
+  cleanUpIdleGamesFlags: CallReducerFlags = 'FullUpdate';
+  cleanUpIdleGames(flags: CallReducerFlags) {
+    this.cleanUpIdleGamesFlags = flags;
+  }
+
+  cleanUpOldChatMessagesFlags: CallReducerFlags = 'FullUpdate';
+  cleanUpOldChatMessages(flags: CallReducerFlags) {
+    this.cleanUpOldChatMessagesFlags = flags;
+  }
+
+  confirmReadyFlags: CallReducerFlags = 'FullUpdate';
+  confirmReady(flags: CallReducerFlags) {
+    this.confirmReadyFlags = flags;
+  }
+
   createGameFlags: CallReducerFlags = 'FullUpdate';
   createGame(flags: CallReducerFlags) {
     this.createGameFlags = flags;
   }
 
+  createGameFromPresetFlags: CallReducerFlags = 'FullUpdate';
+  createGameFromPreset(flags: CallReducerFlags) {
+    this.createGameFromPresetFlags = flags;
+  }
+
+  createTournamentFlags: CallReducerFlags = 'FullUpdate';
+  createTournament(flags: CallReducerFlags) {
+    this.createTournamentFlags = flags;
+  }
+
   deleteGameFlags: CallReducerFlags = 'FullUpdate';
   deleteGame(flags: CallReducerFlags) {
     this.deleteGameFlags = flags;
   }
 
+  deleteSettingsPresetFlags: CallReducerFlags = 'FullUpdate';
+  deleteSettingsPreset(flags: CallReducerFlags) {
+    this.deleteSettingsPresetFlags = flags;
+  }
+
   gameCountdownFlags: CallReducerFlags = 'FullUpdate';
   gameCountdown(flags: CallReducerFlags) {
     this.gameCountdownFlags = flags;
   }
 
+  joinQuickPlayQueueFlags: CallReducerFlags = 'FullUpdate';
+  joinQuickPlayQueue(flags: CallReducerFlags) {
+    this.joinQuickPlayQueueFlags = flags;
+  }
+
+  joinRankedQueueFlags: CallReducerFlags = 'FullUpdate';
+  joinRankedQueue(flags: CallReducerFlags) {
+    this.joinRankedQueueFlags = flags;
+  }
+
+  leaveQuickPlayQueueFlags: CallReducerFlags = 'FullUpdate';
+  leaveQuickPlayQueue(flags: CallReducerFlags) {
+    this.leaveQuickPlayQueueFlags = flags;
+  }
+
+  leaveRankedQueueFlags: CallReducerFlags = 'FullUpdate';
+  leaveRankedQueue(flags: CallReducerFlags) {
+    this.leaveRankedQueueFlags = flags;
+  }
+
+  matchClockTimeoutFlags: CallReducerFlags = 'FullUpdate';
+  matchClockTimeout(flags: CallReducerFlags) {
+    this.matchClockTimeoutFlags = flags;
+  }
+
+  quickPlayMatchmakingFlags: CallReducerFlags = 'FullUpdate';
+  quickPlayMatchmaking(flags: CallReducerFlags) {
+    this.quickPlayMatchmakingFlags = flags;
+  }
+
+  rankedMatchmakingFlags: CallReducerFlags = 'FullUpdate';
+  rankedMatchmaking(flags: CallReducerFlags) {
+    this.rankedMatchmakingFlags = flags;
+  }
+
+  reactFlags: CallReducerFlags = 'FullUpdate';
+  react(flags: CallReducerFlags) {
+    this.reactFlags = flags;
+  }
+
+  readyCheckTimeoutFlags: CallReducerFlags = 'FullUpdate';
+  readyCheckTimeout(flags: CallReducerFlags) {
+    this.readyCheckTimeoutFlags = flags;
+  }
+
+  registerForTournamentFlags: CallReducerFlags = 'FullUpdate';
+  registerForTournament(flags: CallReducerFlags) {
+    this.registerForTournamentFlags = flags;
+  }
+
   registerPlayerFlags: CallReducerFlags = 'FullUpdate';
   registerPlayer(flags: CallReducerFlags) {
     this.registerPlayerFlags = flags;
   }
 
+  removeAdminFlags: CallReducerFlags = 'FullUpdate';
+  removeAdmin(flags: CallReducerFlags) {
+    this.removeAdminFlags = flags;
+  }
+
+  removeBlockedWordFlags: CallReducerFlags = 'FullUpdate';
+  removeBlockedWord(flags: CallReducerFlags) {
+    this.removeBlockedWordFlags = flags;
+  }
+
   removePlayerFlags: CallReducerFlags = 'FullUpdate';
   removePlayer(flags: CallReducerFlags) {
     this.removePlayerFlags = flags;
@@ -615,11 +2117,56 @@ export class SetReducerFlags {
     this.restartGameFlags = flags;
   }
 
+  saveSettingsPresetFlags: CallReducerFlags = 'FullUpdate';
+  saveSettingsPreset(flags: CallReducerFlags) {
+    this.saveSettingsPresetFlags = flags;
+  }
+
+  seedTournamentFlags: CallReducerFlags = 'FullUpdate';
+  seedTournament(flags: CallReducerFlags) {
+    this.seedTournamentFlags = flags;
+  }
+
+  sendChatMessageFlags: CallReducerFlags = 'FullUpdate';
+  sendChatMessage(flags: CallReducerFlags) {
+    this.sendChatMessageFlags = flags;
+  }
+
+  setChatRetentionFlags: CallReducerFlags = 'FullUpdate';
+  setChatRetention(flags: CallReducerFlags) {
+    this.setChatRetentionFlags = flags;
+  }
+
+  setIdleGamePeriodFlags: CallReducerFlags = 'FullUpdate';
+  setIdleGamePeriod(flags: CallReducerFlags) {
+    this.setIdleGamePeriodFlags = flags;
+  }
+
+  setPlayerHandicapFlags: CallReducerFlags = 'FullUpdate';
+  setPlayerHandicap(flags: CallReducerFlags) {
+    this.setPlayerHandicapFlags = flags;
+  }
+
+  setUsernamePolicyFlags: CallReducerFlags = 'FullUpdate';
+  setUsernamePolicy(flags: CallReducerFlags) {
+    this.setUsernamePolicyFlags = flags;
+  }
+
   startGameFlags: CallReducerFlags = 'FullUpdate';
   startGame(flags: CallReducerFlags) {
     this.startGameFlags = flags;
   }
 
+  startNextSeriesGameFlags: CallReducerFlags = 'FullUpdate';
+  startNextSeriesGame(flags: CallReducerFlags) {
+    this.startNextSeriesGameFlags = flags;
+  }
+
+  startTournamentFlags: CallReducerFlags = 'FullUpdate';
+  startTournament(flags: CallReducerFlags) {
+    this.startTournamentFlags = flags;
+  }
+
   submitWordFlags: CallReducerFlags = 'FullUpdate';
   submitWord(flags: CallReducerFlags) {
     this.submitWordFlags = flags;
@@ -635,11 +2182,71 @@ export class SetReducerFlags {
     this.updateBonusLetterWordCountFlags = flags;
   }
 
+  updateChatBlocksTrigramFlags: CallReducerFlags = 'FullUpdate';
+  updateChatBlocksTrigram(flags: CallReducerFlags) {
+    this.updateChatBlocksTrigramFlags = flags;
+  }
+
+  updateChessClockFlags: CallReducerFlags = 'FullUpdate';
+  updateChessClock(flags: CallReducerFlags) {
+    this.updateChessClockFlags = flags;
+  }
+
+  updateContentFilterFlags: CallReducerFlags = 'FullUpdate';
+  updateContentFilter(flags: CallReducerFlags) {
+    this.updateContentFilterFlags = flags;
+  }
+
+  updateCountdownSecondsFlags: CallReducerFlags = 'FullUpdate';
+  updateCountdownSeconds(flags: CallReducerFlags) {
+    this.updateCountdownSecondsFlags = flags;
+  }
+
   updateCurrentWordFlags: CallReducerFlags = 'FullUpdate';
   updateCurrentWord(flags: CallReducerFlags) {
     this.updateCurrentWordFlags = flags;
   }
 
+  updateLimitTiebreakFlags: CallReducerFlags = 'FullUpdate';
+  updateLimitTiebreak(flags: CallReducerFlags) {
+    this.updateLimitTiebreakFlags = flags;
+  }
+
+  updateMatchTimeLimitFlags: CallReducerFlags = 'FullUpdate';
+  updateMatchTimeLimit(flags: CallReducerFlags) {
+    this.updateMatchTimeLimitFlags = flags;
+  }
+
+  updateMaxPlayersFlags: CallReducerFlags = 'FullUpdate';
+  updateMaxPlayers(flags: CallReducerFlags) {
+    this.updateMaxPlayersFlags = flags;
+  }
+
+  updateMaxRoundsFlags: CallReducerFlags = 'FullUpdate';
+  updateMaxRounds(flags: CallReducerFlags) {
+    this.updateMaxRoundsFlags = flags;
+  }
+
+  updateOvertimeEnabledFlags: CallReducerFlags = 'FullUpdate';
+  updateOvertimeEnabled(flags: CallReducerFlags) {
+    this.updateOvertimeEnabledFlags = flags;
+  }
+
+  updatePowerUpsEnabledFlags: CallReducerFlags = 'FullUpdate';
+  updatePowerUpsEnabled(flags: CallReducerFlags) {
+    this.updatePowerUpsEnabledFlags = flags;
+  }
+
+  updateReadyCheckFlags: CallReducerFlags = 'FullUpdate';
+  updateReadyCheck(flags: CallReducerFlags) {
+    this.updateReadyCheckFlags = flags;
+  }
+
+  updateSeriesBestOfFlags: CallReducerFlags = 'FullUpdate';
+  updateSeriesBestOf(flags: CallReducerFlags) {
+    this.updateSeriesBestOfFlags = flags;
+  }
+
   updateStartingLivesFlags: CallReducerFlags = 'FullUpdate';
   updateStartingLives(flags: CallReducerFlags) {
     this.updateStartingLivesFlags = flags;
@@ -655,20 +2262,69 @@ export class SetReducerFlags {
     this.updateTurnTimeoutFlags = flags;
   }
 
+  updateVisibilityFlags: CallReducerFlags = 'FullUpdate';
+  updateVisibility(flags: CallReducerFlags) {
+    this.updateVisibilityFlags = flags;
+  }
+
   updateWinConditionFlags: CallReducerFlags = 'FullUpdate';
   updateWinCondition(flags: CallReducerFlags) {
     this.updateWinConditionFlags = flags;
   }
 
+  updateWordTurnEffectsFlags: CallReducerFlags = 'FullUpdate';
+  updateWordTurnEffects(flags: CallReducerFlags) {
+    this.updateWordTurnEffectsFlags = flags;
+  }
+
+  usePowerUpFlags: CallReducerFlags = 'FullUpdate';
+  usePowerUp(flags: CallReducerFlags) {
+    this.usePowerUpFlags = flags;
+  }
+
+  voteRematchFlags: CallReducerFlags = 'FullUpdate';
+  voteRematch(flags: CallReducerFlags) {
+    this.voteRematchFlags = flags;
+  }
+
+  withdrawFromTournamentFlags: CallReducerFlags = 'FullUpdate';
+  withdrawFromTournament(flags: CallReducerFlags) {
+    this.withdrawFromTournamentFlags = flags;
+  }
+
 }
 
 export class RemoteTables {
   constructor(private connection: DbConnectionImpl) {}
 
+  get admin(): AdminTableHandle {
+    return new AdminTableHandle(this.connection.clientCache.getOrCreateTable<AdminTable>(REMOTE_MODULE.tables.admin));
+  }
+
+  get blockedWord(): BlockedWordTableHandle {
+    return new BlockedWordTableHandle(this.connection.clientCache.getOrCreateTable<BlockedWordTable>(REMOTE_MODULE.tables.blocked_word));
+  }
+
+  get chatCleanupSchedule(): ChatCleanupScheduleTableHandle {
+    return new ChatCleanupScheduleTableHandle(this.connection.clientCache.getOrCreateTable<ChatCleanupSchedule>(REMOTE_MODULE.tables.chat_cleanup_schedule));
+  }
+
+  get chatMessage(): ChatMessageTableHandle {
+    return new ChatMessageTableHandle(this.connection.clientCache.getOrCreateTable<ChatMessageTable>(REMOTE_MODULE.tables.chat_message));
+  }
+
+  get chatRateLimit(): ChatRateLimitTableHandle {
+    return new ChatRateLimitTableHandle(this.connection.clientCache.getOrCreateTable<ChatRateLimitTable>(REMOTE_MODULE.tables.chat_rate_limit));
+  }
+
   get game(): GameTableHandle {
     return new GameTableHandle(this.connection.clientCache.getOrCreateTable<Game>(REMOTE_MODULE.tables.game));
   }
 
+  get gameCleanupSchedule(): GameCleanupScheduleTableHandle {
+    return new GameCleanupScheduleTableHandle(this.connection.clientCache.getOrCreateTable<GameCleanupSchedule>(REMOTE_MODULE.tables.game_cleanup_schedule));
+  }
+
   get gameCountdownSchedule(): GameCountdownScheduleTableHandle {
     return new GameCountdownScheduleTableHandle(this.connection.clientCache.getOrCreateTable<GameCountdownSchedule>(REMOTE_MODULE.tables.game_countdown_schedule));
   }
@@ -677,13 +2333,69 @@ export class RemoteTables {
     return new GameStateTableHandle(this.connection.clientCache.getOrCreateTable<GameStateTable>(REMOTE_MODULE.tables.game_state));
   }
 
+  get leaderboard(): LeaderboardTableHandle {
+    return new LeaderboardTableHandle(this.connection.clientCache.getOrCreateTable<LeaderboardTable>(REMOTE_MODULE.tables.leaderboard));
+  }
+
+  get lobbySummary(): LobbySummaryTableHandle {
+    return new LobbySummaryTableHandle(this.connection.clientCache.getOrCreateTable<LobbySummaryTable>(REMOTE_MODULE.tables.lobby_summary));
+  }
+
+  get matchClockSchedule(): MatchClockScheduleTableHandle {
+    return new MatchClockScheduleTableHandle(this.connection.clientCache.getOrCreateTable<MatchClockSchedule>(REMOTE_MODULE.tables.match_clock_schedule));
+  }
+
   get playerInfo(): PlayerInfoTableHandle {
     return new PlayerInfoTableHandle(this.connection.clientCache.getOrCreateTable<PlayerInfoTable>(REMOTE_MODULE.tables.player_info));
   }
 
+  get quickPlayQueue(): QuickPlayQueueTableHandle {
+    return new QuickPlayQueueTableHandle(this.connection.clientCache.getOrCreateTable<QuickPlayQueueTable>(REMOTE_MODULE.tables.quick_play_queue));
+  }
+
+  get quickPlaySchedule(): QuickPlayScheduleTableHandle {
+    return new QuickPlayScheduleTableHandle(this.connection.clientCache.getOrCreateTable<QuickPlaySchedule>(REMOTE_MODULE.tables.quick_play_schedule));
+  }
+
+  get rankedMatchmakingSchedule(): RankedMatchmakingScheduleTableHandle {
+    return new RankedMatchmakingScheduleTableHandle(this.connection.clientCache.getOrCreateTable<RankedMatchmakingSchedule>(REMOTE_MODULE.tables.ranked_matchmaking_schedule));
+  }
+
+  get rankedQueue(): RankedQueueTableHandle {
+    return new RankedQueueTableHandle(this.connection.clientCache.getOrCreateTable<RankedQueueTable>(REMOTE_MODULE.tables.ranked_queue));
+  }
+
+  get readyCheckSchedule(): ReadyCheckScheduleTableHandle {
+    return new ReadyCheckScheduleTableHandle(this.connection.clientCache.getOrCreateTable<ReadyCheckSchedule>(REMOTE_MODULE.tables.ready_check_schedule));
+  }
+
+  get seriesNextGameSchedule(): SeriesNextGameScheduleTableHandle {
+    return new SeriesNextGameScheduleTableHandle(this.connection.clientCache.getOrCreateTable<SeriesNextGameSchedule>(REMOTE_MODULE.tables.series_next_game_schedule));
+  }
+
+  get settingsPreset(): SettingsPresetTableHandle {
+    return new SettingsPresetTableHandle(this.connection.clientCache.getOrCreateTable<SettingsPresetTable>(REMOTE_MODULE.tables.settings_preset));
+  }
+
+  get tournament(): TournamentTableHandle {
+    return new TournamentTableHandle(this.connection.clientCache.getOrCreateTable<TournamentTable>(REMOTE_MODULE.tables.tournament));
+  }
+
+  get tournamentMatch(): TournamentMatchTableHandle {
+    return new TournamentMatchTableHandle(this.connection.clientCache.getOrCreateTable<TournamentMatchTable>(REMOTE_MODULE.tables.tournament_match));
+  }
+
+  get tournamentParticipant(): TournamentParticipantTableHandle {
+    return new TournamentParticipantTableHandle(this.connection.clientCache.getOrCreateTable<TournamentParticipantTable>(REMOTE_MODULE.tables.tournament_participant));
+  }
+
   get turnTimeoutSchedule(): TurnTimeoutScheduleTableHandle {
     return new TurnTimeoutScheduleTableHandle(this.connection.clientCache.getOrCreateTable<TurnTimeoutSchedule>(REMOTE_MODULE.tables.turn_timeout_schedule));
   }
+
+  get usernamePolicy(): UsernamePolicyTableHandle {
+    return new UsernamePolicyTableHandle(this.connection.clientCache.getOrCreateTable<UsernamePolicy>(REMOTE_MODULE.tables.username_policy));
+  }
 }
 
 export class SubscriptionBuilder extends SubscriptionBuilderImpl<RemoteTables, RemoteReducers, SetReducerFlags> { }
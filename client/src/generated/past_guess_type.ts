@@ -35,6 +35,8 @@ import {
 export type PastGuess = {
   word: string,
   roundNumber: number,
+  turnStartedAt: Timestamp,
+  guessedAt: Timestamp,
 };
 
 /**
@@ -49,6 +51,8 @@ export namespace PastGuess {
     return AlgebraicType.createProductType([
       new ProductTypeElement("word", AlgebraicType.createStringType()),
       new ProductTypeElement("roundNumber", AlgebraicType.createU32Type()),
+      new ProductTypeElement("turnStartedAt", AlgebraicType.createTimestampType()),
+      new ProductTypeElement("guessedAt", AlgebraicType.createTimestampType()),
     ]);
   }
 
@@ -35,6 +35,8 @@ import {
 import { PlayerWinConditionData as __PlayerWinConditionData } from "./player_win_condition_data_type";
 import { PastGuess as __PastGuess } from "./past_guess_type";
 import { GameStateEvent as __GameStateEvent } from "./game_state_event_type";
+import { PowerUp as __PowerUp } from "./power_up_type";
+import { PlayerHandicap as __PlayerHandicap } from "./player_handicap_type";
 
 export type PlayerGameData = {
   playerIdentity: Identity,
@@ -44,6 +46,14 @@ export type PlayerGameData = {
   freeLetters: string[],
   pastGuesses: __PastGuess[],
   events: __GameStateEvent[],
+  eliminatedTurn: number | undefined,
+  fastestAnswer: TimeDuration | undefined,
+  averageAnswer: TimeDuration | undefined,
+  powerUps: __PowerUp[],
+  shieldActive: boolean,
+  handicap: __PlayerHandicap,
+  timeBank: TimeDuration,
+  turnsTaken: number,
 };
 
 /**
@@ -63,6 +73,14 @@ export namespace PlayerGameData {
       new ProductTypeElement("freeLetters", AlgebraicType.createArrayType(AlgebraicType.createStringType())),
       new ProductTypeElement("pastGuesses", AlgebraicType.createArrayType(__PastGuess.getTypeScriptAlgebraicType())),
       new ProductTypeElement("events", AlgebraicType.createArrayType(__GameStateEvent.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("eliminatedTurn", AlgebraicType.createOptionType(AlgebraicType.createU32Type())),
+      new ProductTypeElement("fastestAnswer", AlgebraicType.createOptionType(AlgebraicType.createTimeDurationType())),
+      new ProductTypeElement("averageAnswer", AlgebraicType.createOptionType(AlgebraicType.createTimeDurationType())),
+      new ProductTypeElement("powerUps", AlgebraicType.createArrayType(__PowerUp.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("shieldActive", AlgebraicType.createBoolType()),
+      new ProductTypeElement("handicap", __PlayerHandicap.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("timeBank", AlgebraicType.createTimeDurationType()),
+      new ProductTypeElement("turnsTaken", AlgebraicType.createU32Type()),
     ]);
   }
 
@@ -35,8 +35,10 @@ import {
 export type PlayerInfoTable = {
   identity: Identity,
   username: string,
+  usernameKey: string,
   isOnline: boolean,
   lastActive: Timestamp,
+  renamedAt: Timestamp | undefined,
 };
 
 /**
@@ -51,8 +53,10 @@ export namespace PlayerInfoTable {
     return AlgebraicType.createProductType([
       new ProductTypeElement("identity", AlgebraicType.createIdentityType()),
       new ProductTypeElement("username", AlgebraicType.createStringType()),
+      new ProductTypeElement("usernameKey", AlgebraicType.createStringType()),
       new ProductTypeElement("isOnline", AlgebraicType.createBoolType()),
       new ProductTypeElement("lastActive", AlgebraicType.createTimestampType()),
+      new ProductTypeElement("renamedAt", AlgebraicType.createOptionType(AlgebraicType.createTimestampType())),
     ]);
   }
 
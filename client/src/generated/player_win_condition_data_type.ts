@@ -39,6 +39,7 @@ export namespace PlayerWinConditionData {
   // the tagged union.
   export type LastPlayerStanding = { tag: "LastPlayerStanding", value: number };
   export type UseAllLetters = { tag: "UseAllLetters" };
+  export type Points = { tag: "Points", value: number };
 
   // Helper functions for constructing each variant of the tagged union.
   // ```
@@ -48,11 +49,13 @@ export namespace PlayerWinConditionData {
   // ```
   export const LastPlayerStanding = (value: number): PlayerWinConditionData => ({ tag: "LastPlayerStanding", value });
   export const UseAllLetters = { tag: "UseAllLetters" };
+  export const Points = (value: number): PlayerWinConditionData => ({ tag: "Points", value });
 
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createSumType([
       new SumTypeVariant("LastPlayerStanding", AlgebraicType.createI32Type()),
       new SumTypeVariant("UseAllLetters", AlgebraicType.createProductType([])),
+      new SumTypeVariant("Points", AlgebraicType.createU32Type()),
     ]);
   }
 
@@ -67,7 +70,7 @@ export namespace PlayerWinConditionData {
 }
 
 // The tagged union or sum type for the algebraic type `PlayerWinConditionData`.
-export type PlayerWinConditionData = PlayerWinConditionData.LastPlayerStanding | PlayerWinConditionData.UseAllLetters;
+export type PlayerWinConditionData = PlayerWinConditionData.LastPlayerStanding | PlayerWinConditionData.UseAllLetters | PlayerWinConditionData.Points;
 
 export default PlayerWinConditionData;
 
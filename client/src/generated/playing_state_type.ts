@@ -34,9 +34,11 @@ import {
 } from "@clockworklabs/spacetimedb-sdk";
 import { SettingsState as __SettingsState } from "./settings_state_type";
 import { PlayerGameData as __PlayerGameData } from "./player_game_data_type";
+import { Reaction as __Reaction } from "./reaction_type";
 import { TurnLogic as __TurnLogic } from "./turn_logic_type";
 import { TrigramExample as __TrigramExample } from "./trigram_example_type";
 import { GameResult as __GameResult } from "./game_result_type";
+import { PlayerPlacement as __PlayerPlacement } from "./player_placement_type";
 
 export type PlayingState = {
   players: __PlayerGameData[],
@@ -44,8 +46,16 @@ export type PlayingState = {
   turnNumber: number,
   settings: __SettingsState,
   currentTrigram: string,
+  startedAt: Timestamp,
+  turnStartedAt: Timestamp,
   trigramExamples: __TrigramExample[],
   winner: __GameResult,
+  placements: __PlayerPlacement[],
+  overtimeRounds: number,
+  extraTimeSeconds: number,
+  nextTrigramHard: boolean,
+  rematchVotes: Identity[],
+  reactions: __Reaction[],
 };
 
 /**
@@ -63,8 +73,16 @@ export namespace PlayingState {
       new ProductTypeElement("turnNumber", AlgebraicType.createU32Type()),
       new ProductTypeElement("settings", __SettingsState.getTypeScriptAlgebraicType()),
       new ProductTypeElement("currentTrigram", AlgebraicType.createStringType()),
+      new ProductTypeElement("startedAt", AlgebraicType.createTimestampType()),
+      new ProductTypeElement("turnStartedAt", AlgebraicType.createTimestampType()),
       new ProductTypeElement("trigramExamples", AlgebraicType.createArrayType(__TrigramExample.getTypeScriptAlgebraicType())),
       new ProductTypeElement("winner", __GameResult.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("placements", AlgebraicType.createArrayType(__PlayerPlacement.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("overtimeRounds", AlgebraicType.createU32Type()),
+      new ProductTypeElement("extraTimeSeconds", AlgebraicType.createU32Type()),
+      new ProductTypeElement("nextTrigramHard", AlgebraicType.createBoolType()),
+      new ProductTypeElement("rematchVotes", AlgebraicType.createArrayType(AlgebraicType.createIdentityType())),
+      new ProductTypeElement("reactions", AlgebraicType.createArrayType(__Reaction.getTypeScriptAlgebraicType())),
     ]);
   }
 
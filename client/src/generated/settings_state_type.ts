@@ -35,6 +35,9 @@ import {
 import { PlayerGameData as __PlayerGameData } from "./player_game_data_type";
 import { WinCondition as __WinCondition } from "./win_condition_type";
 import { TurnLogicMode as __TurnLogicMode } from "./turn_logic_mode_type";
+import { TiebreakRule as __TiebreakRule } from "./tiebreak_rule_type";
+import { ChessClockSettings as __ChessClockSettings } from "./chess_clock_settings_type";
+import { LobbyVisibility as __LobbyVisibility } from "./lobby_visibility_type";
 
 export type SettingsState = {
   turnTimeoutSeconds: number,
@@ -42,6 +45,20 @@ export type SettingsState = {
   winCondition: __WinCondition,
   turnLogicMode: __TurnLogicMode,
   bonusLetterWordCount: number | undefined,
+  maxRounds: number | undefined,
+  matchTimeLimitSeconds: number | undefined,
+  limitTiebreak: __TiebreakRule,
+  overtimeEnabled: boolean,
+  powerUpsEnabled: boolean,
+  wordTurnEffects: boolean,
+  chessClock: __ChessClockSettings | undefined,
+  countdownSeconds: number,
+  readyCheckSeconds: number | undefined,
+  seriesBestOf: number | undefined,
+  visibility: __LobbyVisibility,
+  maxPlayers: number,
+  chatBlocksTrigram: boolean,
+  contentFilter: boolean,
 };
 
 /**
@@ -59,6 +76,20 @@ export namespace SettingsState {
       new ProductTypeElement("winCondition", __WinCondition.getTypeScriptAlgebraicType()),
       new ProductTypeElement("turnLogicMode", __TurnLogicMode.getTypeScriptAlgebraicType()),
       new ProductTypeElement("bonusLetterWordCount", AlgebraicType.createOptionType(AlgebraicType.createU32Type())),
+      new ProductTypeElement("maxRounds", AlgebraicType.createOptionType(AlgebraicType.createU32Type())),
+      new ProductTypeElement("matchTimeLimitSeconds", AlgebraicType.createOptionType(AlgebraicType.createU32Type())),
+      new ProductTypeElement("limitTiebreak", __TiebreakRule.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("overtimeEnabled", AlgebraicType.createBoolType()),
+      new ProductTypeElement("powerUpsEnabled", AlgebraicType.createBoolType()),
+      new ProductTypeElement("wordTurnEffects", AlgebraicType.createBoolType()),
+      new ProductTypeElement("chessClock", AlgebraicType.createOptionType(__ChessClockSettings.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("countdownSeconds", AlgebraicType.createU32Type()),
+      new ProductTypeElement("readyCheckSeconds", AlgebraicType.createOptionType(AlgebraicType.createU32Type())),
+      new ProductTypeElement("seriesBestOf", AlgebraicType.createOptionType(AlgebraicType.createU32Type())),
+      new ProductTypeElement("visibility", __LobbyVisibility.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("maxPlayers", AlgebraicType.createU32Type()),
+      new ProductTypeElement("chatBlocksTrigram", AlgebraicType.createBoolType()),
+      new ProductTypeElement("contentFilter", AlgebraicType.createBoolType()),
     ]);
   }
 
@@ -32,6 +32,8 @@ import {
   type ReducerEventContextInterface,
   type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { PointsGoal as __PointsGoal } from "./points_goal_type";
+
 // A namespace for generated variants and helper functions.
 export namespace WinCondition {
   // These are the generated variant types for each variant of the tagged union.
@@ -39,6 +41,7 @@ export namespace WinCondition {
   // the tagged union.
   export type LastPlayerStanding = { tag: "LastPlayerStanding", value: number };
   export type UseAllLetters = { tag: "UseAllLetters" };
+  export type Points = { tag: "Points", value: __PointsGoal };
 
   // Helper functions for constructing each variant of the tagged union.
   // ```
@@ -48,11 +51,13 @@ export namespace WinCondition {
   // ```
   export const LastPlayerStanding = (value: number): WinCondition => ({ tag: "LastPlayerStanding", value });
   export const UseAllLetters = { tag: "UseAllLetters" };
+  export const Points = (value: __PointsGoal): WinCondition => ({ tag: "Points", value });
 
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createSumType([
       new SumTypeVariant("LastPlayerStanding", AlgebraicType.createU32Type()),
       new SumTypeVariant("UseAllLetters", AlgebraicType.createProductType([])),
+      new SumTypeVariant("Points", __PointsGoal.getTypeScriptAlgebraicType()),
     ]);
   }
 
@@ -67,7 +72,7 @@ export namespace WinCondition {
 }
 
 // The tagged union or sum type for the algebraic type `WinCondition`.
-export type WinCondition = WinCondition.LastPlayerStanding | WinCondition.UseAllLetters;
+export type WinCondition = WinCondition.LastPlayerStanding | WinCondition.UseAllLetters | WinCondition.Points;
 
 export default WinCondition;
 
@@ -79,6 +79,36 @@ export function setupSoundEffects(currentPlayerIdentity: Identity | undefined):
         case 'FreeLetterAward':
           playGameSound('sounds/Complete and Success/Success 3.m4a');
           break;
+        case 'Overtime':
+          playGameSound('sounds/Notifications and Alerts/Alert 1.m4a');
+          break;
+        case 'PowerUpAwarded':
+          // Only play power-up sound for the player who earned it
+          if (isThisEventForMe(playerIdentity, currentPlayerIdentity)) {
+            playGameSound('sounds/Complete and Success/Complete 1.m4a');
+          }
+          break;
+        case 'PowerUpUsed':
+          playGameSound('sounds/Buttons and Navigation/Button 3.m4a');
+          break;
+        case 'ShieldBlocked':
+          playGameSound('sounds/Complete and Success/Complete 2.m4a');
+          break;
+        case 'TurnOrderReversed':
+          playGameSound('sounds/Buttons and Navigation/Tab 1.m4a');
+          break;
+        case 'PlayerSkipped':
+          // Only play skip sound for the player who was skipped
+          if (isThisEventForMe(event.value, currentPlayerIdentity)) {
+            playGameSound('sounds/Errors and Cancel/Error 3.m4a');
+          }
+          break;
+        case 'BombPassedBack':
+          playGameSound('sounds/Notifications and Alerts/Alert 2.m4a');
+          break;
+        case 'Reaction':
+          playGameSound('sounds/Notifications and Alerts/Notification 1.m4a');
+          break;
         default:
           assertNever(event);
       }
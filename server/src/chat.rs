@@ -0,0 +1,263 @@
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+
+use crate::content_filter::require_admin;
+use crate::{game, game_state, player_info, GameState};
+
+const MAX_CHAT_MESSAGE_LENGTH: usize = 200;
+// Each player may send this many messages per rate limit window
+const CHAT_RATE_LIMIT_MESSAGES: u32 = 5;
+const CHAT_RATE_LIMIT_WINDOW_SECONDS: i64 = 10;
+// Messages are deleted once they are this old. Admins can change it with
+// set_chat_retention.
+const DEFAULT_CHAT_RETENTION_SECONDS: u32 = 60 * 60;
+const MIN_CHAT_RETENTION_SECONDS: u32 = 60;
+const CHAT_CLEANUP_INTERVAL_SECONDS: i64 = 60;
+
+#[spacetimedb::table(name = chat_message, public)]
+pub struct ChatMessageTable {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub game_id: Option<u32>, // None for the global lobby chat
+    pub sender: Identity,
+    pub text: String,
+    pub sent_at: Timestamp,
+}
+
+#[spacetimedb::table(name = chat_rate_limit)]
+pub struct ChatRateLimitTable {
+    #[primary_key]
+    pub identity: Identity,
+    pub window_started_at: Timestamp,
+    pub messages_in_window: u32,
+}
+
+#[spacetimedb::table(name = chat_cleanup_schedule, scheduled(clean_up_old_chat_messages))]
+pub struct ChatCleanupSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    retention_seconds: u32, // How long a message is kept
+}
+
+// Helper function to start the recurring chat message cleanup
+pub fn schedule_chat_cleanup(ctx: &ReducerContext) {
+    let interval_micros = CHAT_CLEANUP_INTERVAL_SECONDS * 1_000_000;
+    ctx.db.chat_cleanup_schedule().insert(ChatCleanupSchedule {
+        scheduled_id: 0, // Auto-incremented
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(interval_micros)),
+        retention_seconds: DEFAULT_CHAT_RETENTION_SECONDS,
+    });
+}
+
+// Helper function to delete a game's chat along with the game
+pub fn delete_game_messages(ctx: &ReducerContext, game_id: u32) {
+    let message_ids: Vec<u64> = ctx
+        .db
+        .chat_message()
+        .iter()
+        .filter(|message| message.game_id == Some(game_id))
+        .map(|message| message.id)
+        .collect();
+    for id in message_ids {
+        ctx.db.chat_message().id().delete(id);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn set_chat_retention(ctx: &ReducerContext, retention_seconds: u32) -> Result<(), String> {
+    require_admin(ctx)?;
+    if retention_seconds < MIN_CHAT_RETENTION_SECONDS {
+        return Err(format!(
+            "Chat retention must be at least {} seconds",
+            MIN_CHAT_RETENTION_SECONDS
+        ));
+    }
+    let schedules: Vec<ChatCleanupSchedule> = ctx.db.chat_cleanup_schedule().iter().collect();
+    for schedule in schedules {
+        ctx.db
+            .chat_cleanup_schedule()
+            .scheduled_id()
+            .update(ChatCleanupSchedule {
+                retention_seconds,
+                ..schedule
+            });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn clean_up_old_chat_messages(
+    ctx: &ReducerContext,
+    arg: ChatCleanupSchedule,
+) -> Result<(), String> {
+    let retention_micros = arg.retention_seconds as i64 * 1_000_000;
+    let now_micros = ctx.timestamp.to_micros_since_unix_epoch();
+    let expired: Vec<u64> = ctx
+        .db
+        .chat_message()
+        .iter()
+        .filter(|message| {
+            now_micros - message.sent_at.to_micros_since_unix_epoch() >= retention_micros
+        })
+        .map(|message| message.id)
+        .collect();
+    for id in expired {
+        ctx.db.chat_message().id().delete(id);
+    }
+    Ok(())
+}
+
+// Sends a chat message to a game, or to the global lobby when game_id is None
+#[spacetimedb::reducer]
+pub fn send_chat_message(
+    ctx: &ReducerContext,
+    game_id: Option<u32>,
+    text: String,
+) -> Result<(), String> {
+    if ctx.db.player_info().identity().find(ctx.sender).is_none() {
+        return Err("Player must be registered to chat".to_string());
+    }
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Err("Message cannot be empty".to_string());
+    }
+    if text.chars().count() > MAX_CHAT_MESSAGE_LENGTH {
+        return Err(format!(
+            "Message can be at most {} characters",
+            MAX_CHAT_MESSAGE_LENGTH
+        ));
+    }
+
+    if let Some(game_id) = game_id {
+        let game = ctx.db.game().id().find(game_id).ok_or("Game not found")?;
+        let game_state = ctx
+            .db
+            .game_state()
+            .game_id()
+            .find(game_id)
+            .ok_or("Game not initialized")?;
+        if !game.player_identities.contains(&ctx.sender)
+            && !game_state.spectators.contains(&ctx.sender)
+        {
+            return Err("Only players and spectators can chat in a game".to_string());
+        }
+        // Keep players from giving away answers by naming the trigram in play
+        match &game_state.state {
+            GameState::Playing(state) => {
+                if state.settings.chat_blocks_trigram
+                    && !state.current_trigram.is_empty()
+                    && text.to_uppercase().contains(&state.current_trigram)
+                {
+                    return Err("Messages cannot contain the current trigram".to_string());
+                }
+            }
+            // There is no trigram to give away outside of play
+            GameState::Settings(_) => {}
+            GameState::ReadyCheck(_) => {}
+            GameState::Countdown(_) => {}
+        }
+    }
+
+    check_rate_limit(ctx)?;
+    ctx.db.chat_message().insert(ChatMessageTable {
+        id: 0, // Auto-incremented
+        game_id,
+        sender: ctx.sender,
+        text,
+        sent_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+// Helper function to count a message against the sender's rate limit window
+fn check_rate_limit(ctx: &ReducerContext) -> Result<(), String> {
+    let existing = ctx.db.chat_rate_limit().identity().find(ctx.sender);
+    let is_new = existing.is_none();
+    let limit = count_message(existing, ctx.sender, ctx.timestamp)?;
+    if is_new {
+        ctx.db.chat_rate_limit().insert(limit);
+    } else {
+        ctx.db.chat_rate_limit().identity().update(limit);
+    }
+    Ok(())
+}
+
+// Helper function to add a message to a rate limit window, starting a new window when the
+// old one has run out
+fn count_message(
+    limit: Option<ChatRateLimitTable>,
+    sender: Identity,
+    now: Timestamp,
+) -> Result<ChatRateLimitTable, String> {
+    let window_micros = CHAT_RATE_LIMIT_WINDOW_SECONDS * 1_000_000;
+    let now_micros = now.to_micros_since_unix_epoch();
+    match limit {
+        Some(mut limit)
+            if now_micros - limit.window_started_at.to_micros_since_unix_epoch()
+                < window_micros =>
+        {
+            if limit.messages_in_window >= CHAT_RATE_LIMIT_MESSAGES {
+                return Err("Sending messages too quickly, slow down".to_string());
+            }
+            limit.messages_in_window += 1;
+            Ok(limit)
+        }
+        Some(_) | None => Ok(ChatRateLimitTable {
+            identity: sender,
+            window_started_at: now,
+            messages_in_window: 1,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at_second(second: i64) -> Timestamp {
+        Timestamp::from_micros_since_unix_epoch(second * 1_000_000)
+    }
+
+    // Helper function to send a message every second starting at `start`
+    fn send_each_second(
+        mut limit: Option<ChatRateLimitTable>,
+        start: i64,
+        count: i64,
+    ) -> Result<ChatRateLimitTable, String> {
+        let sender = Identity::from_byte_array([1; 32]);
+        for second in start..start + count {
+            limit = Some(count_message(limit, sender, at_second(second))?);
+        }
+        Ok(limit.unwrap())
+    }
+
+    #[test]
+    fn chat_allows_the_limit_within_one_window() {
+        let limit = send_each_second(None, 0, CHAT_RATE_LIMIT_MESSAGES as i64).unwrap();
+        assert_eq!(limit.messages_in_window, CHAT_RATE_LIMIT_MESSAGES);
+        assert_eq!(limit.window_started_at, at_second(0));
+    }
+
+    #[test]
+    fn chat_rejects_messages_over_the_limit() {
+        assert!(send_each_second(None, 0, CHAT_RATE_LIMIT_MESSAGES as i64 + 1).is_err());
+    }
+
+    #[test]
+    fn chat_starts_a_new_window_once_the_old_one_runs_out() {
+        let full = send_each_second(None, 0, CHAT_RATE_LIMIT_MESSAGES as i64).unwrap();
+        let limit = count_message(
+            Some(full),
+            Identity::from_byte_array([1; 32]),
+            at_second(CHAT_RATE_LIMIT_WINDOW_SECONDS),
+        )
+        .unwrap();
+        assert_eq!(limit.messages_in_window, 1);
+        assert_eq!(
+            limit.window_started_at,
+            at_second(CHAT_RATE_LIMIT_WINDOW_SECONDS)
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use spacetimedb::{ReducerContext, ScheduleAt, Table, TimeDuration};
+
+use crate::content_filter::require_admin;
+use crate::{delete_game_rows, game, player_info};
+
+// Games with nobody online are deleted once they have been idle this long. Admins can
+// change it with set_idle_game_period.
+const DEFAULT_IDLE_GAME_SECONDS: u32 = 30 * 60;
+const MIN_IDLE_GAME_SECONDS: u32 = 60;
+const GAME_CLEANUP_INTERVAL_SECONDS: i64 = 60;
+
+#[spacetimedb::table(name = game_cleanup_schedule, scheduled(clean_up_idle_games))]
+pub struct GameCleanupSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    idle_seconds: u32, // How long a game with no online players is kept
+}
+
+// Helper function to start the recurring idle game cleanup
+pub fn schedule_game_cleanup(ctx: &ReducerContext) {
+    let interval_micros = GAME_CLEANUP_INTERVAL_SECONDS * 1_000_000;
+    ctx.db.game_cleanup_schedule().insert(GameCleanupSchedule {
+        scheduled_id: 0, // Auto-incremented
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(interval_micros)),
+        idle_seconds: DEFAULT_IDLE_GAME_SECONDS,
+    });
+}
+
+#[spacetimedb::reducer]
+pub fn set_idle_game_period(ctx: &ReducerContext, idle_seconds: u32) -> Result<(), String> {
+    require_admin(ctx)?;
+    if idle_seconds < MIN_IDLE_GAME_SECONDS {
+        return Err(format!(
+            "Idle period must be at least {} seconds",
+            MIN_IDLE_GAME_SECONDS
+        ));
+    }
+    let schedules: Vec<GameCleanupSchedule> = ctx.db.game_cleanup_schedule().iter().collect();
+    for schedule in schedules {
+        ctx.db
+            .game_cleanup_schedule()
+            .scheduled_id()
+            .update(GameCleanupSchedule {
+                idle_seconds,
+                ..schedule
+            });
+    }
+    Ok(())
+}
+
+// Deletes games in any state, including stuck Playing games, once none of their players
+// are online and none has been seen for the idle period. Idleness is measured from the
+// players rather than the game rows, which scheduled reducers keep touching.
+#[spacetimedb::reducer]
+pub fn clean_up_idle_games(ctx: &ReducerContext, arg: GameCleanupSchedule) -> Result<(), String> {
+    let idle_micros = arg.idle_seconds as i64 * 1_000_000;
+    let now_micros = ctx.timestamp.to_micros_since_unix_epoch();
+    let abandoned_games: Vec<u32> = ctx
+        .db
+        .game()
+        .iter()
+        .filter(|game| {
+            let players: Vec<_> = game
+                .player_identities
+                .iter()
+                .filter_map(|identity| ctx.db.player_info().identity().find(*identity))
+                .collect();
+            let anyone_online = players.iter().any(|info| info.is_online);
+            let last_active = players
+                .iter()
+                .map(|info| info.last_active)
+                .fold(game.created_at, |latest, seen| latest.max(seen));
+            !anyone_online && now_micros - last_active.to_micros_since_unix_epoch() >= idle_micros
+        })
+        .map(|game| game.id)
+        .collect();
+    // One game failing to clean up shouldn't roll back the rest of the sweep
+    for game_id in abandoned_games {
+        match delete_game_rows(ctx, game_id) {
+            Ok(()) => {}
+            Err(e) => log::error!("Failed to clean up idle game {}: {}", game_id, e),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,217 @@
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+// Dictionary words games with the content filter on start out blocking. Words are matched
+// whole, so each form of a word is listed and real words that merely start with one, like
+// RETARDANT or WANKEL, stay playable.
+const SEEDED_BLOCKED_WORDS: &[&str] = &[
+    "ASSHOLE",
+    "ASSHOLES",
+    "BITCH",
+    "BITCHES",
+    "BITCHING",
+    "BITCHY",
+    "BOLLOCKS",
+    "CUNT",
+    "CUNTS",
+    "DICKHEAD",
+    "DICKHEADS",
+    "FAGGOT",
+    "FAGGOTS",
+    "FUCK",
+    "FUCKED",
+    "FUCKER",
+    "FUCKERS",
+    "FUCKING",
+    "FUCKS",
+    "MOTHERFUCKER",
+    "MOTHERFUCKERS",
+    "NIGGA",
+    "NIGGAS",
+    "NIGGER",
+    "NIGGERS",
+    "PORN",
+    "PORNO",
+    "PORNOGRAPHIC",
+    "PORNOGRAPHY",
+    "PUSSIES",
+    "PUSSY",
+    "RETARD",
+    "RETARDS",
+    "SHIT",
+    "SHITS",
+    "SHITTED",
+    "SHITTING",
+    "SHITTY",
+    "SLUT",
+    "SLUTS",
+    "SLUTTY",
+    "TWAT",
+    "TWATS",
+    "WANK",
+    "WANKED",
+    "WANKER",
+    "WANKERS",
+    "WANKING",
+    "WANKS",
+    "WHORE",
+    "WHORES",
+];
+
+// Players allowed to edit the blocked word list. Whoever publishes the module is the
+// first admin.
+#[spacetimedb::table(name = admin, public)]
+pub struct AdminTable {
+    #[primary_key]
+    pub identity: Identity,
+    pub added_at: Timestamp,
+}
+
+// Words games with the content filter on won't accept or show as examples
+#[spacetimedb::table(name = blocked_word, public)]
+pub struct BlockedWordTable {
+    #[primary_key]
+    pub word: String, // Uppercase, like the dictionary
+    pub added_by: Identity,
+    pub added_at: Timestamp,
+}
+
+// Helper function to make the publisher an admin and start the blocked word list
+pub fn seed_content_filter(ctx: &ReducerContext) {
+    ctx.db.admin().insert(AdminTable {
+        identity: ctx.sender,
+        added_at: ctx.timestamp,
+    });
+    for word in SEEDED_BLOCKED_WORDS {
+        ctx.db.blocked_word().insert(BlockedWordTable {
+            word: word.to_string(),
+            added_by: ctx.sender,
+            added_at: ctx.timestamp,
+        });
+    }
+}
+
+// Helper function to check an uppercase dictionary word against the blocked list
+pub fn is_blocked(ctx: &ReducerContext, word: &str) -> bool {
+    ctx.db
+        .blocked_word()
+        .word()
+        .find(word.to_string())
+        .is_some()
+}
+
+// Helper function to check the sender is an admin
+pub fn require_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.admin().identity().find(ctx.sender).is_none() {
+        return Err("Only admins can do that".to_string());
+    }
+    Ok(())
+}
+
+// Helper function to normalize a word the way the dictionary stores it
+fn normalize_word(word: &str) -> Result<String, String> {
+    let word = word.trim().to_uppercase();
+    if word.is_empty() || !word.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err("Blocked words must be a single word of letters".to_string());
+    }
+    Ok(word)
+}
+
+#[spacetimedb::reducer]
+pub fn add_blocked_word(ctx: &ReducerContext, word: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    let word = normalize_word(&word)?;
+    if ctx.db.blocked_word().word().find(&word).is_some() {
+        return Err("Word is already blocked".to_string());
+    }
+    ctx.db.blocked_word().insert(BlockedWordTable {
+        word,
+        added_by: ctx.sender,
+        added_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_blocked_word(ctx: &ReducerContext, word: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    let word = normalize_word(&word)?;
+    if !ctx.db.blocked_word().word().delete(&word) {
+        return Err("Word is not blocked".to_string());
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn add_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.admin().identity().find(identity).is_some() {
+        return Err("Player is already an admin".to_string());
+    }
+    ctx.db.admin().insert(AdminTable {
+        identity,
+        added_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if identity == ctx.sender {
+        return Err("Admins cannot remove themselves".to_string());
+    }
+    if !ctx.db.admin().identity().delete(identity) {
+        return Err("Player is not an admin".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded(word: &str) -> bool {
+        SEEDED_BLOCKED_WORDS.contains(&word)
+    }
+
+    #[test]
+    fn seeded_words_are_stored_the_way_admins_add_them() {
+        for word in SEEDED_BLOCKED_WORDS {
+            assert_eq!(normalize_word(word).as_deref(), Ok(*word));
+        }
+    }
+
+    #[test]
+    fn seeded_list_blocks_each_form_of_a_word() {
+        assert!(seeded("FUCK"));
+        assert!(seeded("FUCKING"));
+        assert!(seeded("SHITTY"));
+        assert!(seeded("WANKER"));
+    }
+
+    #[test]
+    fn real_dictionary_words_are_not_blocked() {
+        for word in [
+            "RETARDANT",
+            "RETARDATION",
+            "FAGOT",
+            "WANKEL",
+            "PENISES",
+            "DICKENS",
+            "SHIITAKE",
+            "SCUNTHORPE",
+            "TWATTLE",
+            "ASSASSIN",
+        ] {
+            assert!(!seeded(word), "{} should be playable", word);
+        }
+    }
+
+    #[test]
+    fn blocked_words_must_be_a_single_word_of_letters() {
+        assert_eq!(normalize_word("  shit ").as_deref(), Ok("SHIT"));
+        assert!(normalize_word("").is_err());
+        assert!(normalize_word("two words").is_err());
+        assert!(normalize_word("sh1t").is_err());
+    }
+}
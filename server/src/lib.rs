@@ -3,12 +3,25 @@ use spacetimedb::{
     Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp,
 };
 
+mod chat;
+mod cleanup;
+mod content_filter;
+mod lobby;
+mod matchmaking;
+mod presets;
+mod profanity;
+mod rating;
+mod tournament;
 mod trigram;
+mod username;
+mod validation;
 
 #[derive(Clone, SpacetimeType)]
 pub struct PastGuess {
     pub word: String,
     pub round_number: u32,
+    pub turn_started_at: Timestamp, // When the turn this guess answered began
+    pub guessed_at: Timestamp,
 }
 
 #[derive(Clone, SpacetimeType)]
@@ -20,12 +33,39 @@ pub struct PlayerGameData {
     pub free_letters: Vec<String>, // Track letters that were awarded for free
     pub past_guesses: Vec<PastGuess>, // Stack of past guesses (most recent last)
     pub events: Vec<GameStateEvent>, // Events for this player (moved from PlayingState)
+    pub eliminated_turn: Option<u32>, // Turn this player ran out of lives on
+    pub fastest_answer: Option<TimeDuration>, // Quickest accepted guess this game
+    pub average_answer: Option<TimeDuration>, // Mean time to an accepted guess this game
+    pub power_ups: Vec<PowerUp>,   // Power-ups earned but not yet used
+    pub shield_active: bool,       // Next life lost is blocked instead
+    pub handicap: PlayerHandicap,  // Per-player adjustments set in the lobby
+    pub time_bank: TimeDuration,   // Time left on this player's chess clock
+    pub turns_taken: u32,          // Classic turns this player has finished
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub struct PlayerHandicap {
+    pub extra_seconds: u32,         // Added to this player's Classic turns
+    pub extra_lives: u32,           // Added to starting lives (LastPlayerStanding)
+    pub starting_free_letters: u32, // Random free letters given when the game starts
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum PowerUp {
+    ExtraTime,          // Adds time to the current turn
+    SkipTrigram,        // Swaps the current trigram for a new one (Classic)
+    ReverseTurnOrder,   // Flips the direction turns pass in (Classic)
+    HardTrigramForNext, // The next trigram drawn comes from the hard pool
+    ShieldLife,         // Blocks the next life lost (LastPlayerStanding)
+    SkipNextPlayer,     // The player after you loses their turn (Classic)
+    PassBombBack,       // The bomb goes back to the previous player (Classic)
 }
 
 #[derive(Clone, SpacetimeType)]
 pub enum PlayerWinConditionData {
     LastPlayerStanding { lives: i32 },
     UseAllLetters,
+    Points { score: u32 },
 }
 
 #[spacetimedb::table(name = player_info, public)]
@@ -33,14 +73,31 @@ pub struct PlayerInfoTable {
     #[primary_key]
     pub identity: Identity,
     pub username: String,
+    #[index(btree)]
+    pub username_key: String, // Lowercased username, used to keep names unique
     pub is_online: bool,
     pub last_active: Timestamp,
+    pub renamed_at: Option<Timestamp>, // When the player last changed their name
 }
 
 #[derive(Clone, SpacetimeType)]
 pub struct ClassicTurnLogic {
     pub current_turn_index: u32,
     pub failed_players: Vec<Identity>,
+    pub direction: TurnDirection,
+    pub pending_effect: Option<TurnEffect>, // Changes who goes next when the turn ends
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum TurnDirection {
+    Forward,
+    Backward,
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum TurnEffect {
+    SkipNextPlayer,
+    PassBombBack,
 }
 
 #[derive(Clone, SpacetimeType)]
@@ -73,6 +130,14 @@ pub struct PlayerWins {
     pub wins: u32,
 }
 
+#[derive(Clone, SpacetimeType)]
+pub struct PlayerPlacement {
+    pub player_identity: Identity,
+    pub placement: u32, // 1 is best, tied players share a placement
+    pub eliminated_turn: Option<u32>,
+    pub letters_used: u32, // Distinct letters used or awarded when the game ended
+}
+
 #[derive(Clone, SpacetimeType)]
 pub enum GameResult {
     Winner(Identity),
@@ -80,9 +145,12 @@ pub enum GameResult {
     None,
 }
 
+// The table stores one row per game, so the variant size difference doesn't matter
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, SpacetimeType)]
 pub enum GameState {
     Settings(SettingsState),
+    ReadyCheck(ReadyCheckState),
     Countdown(CountdownState),
     Playing(PlayingState),
 }
@@ -94,14 +162,29 @@ pub struct PlayingState {
     pub turn_number: u32,      // Total number of turns that have occurred
     pub settings: SettingsState, // Settings preserved from settings state
     pub current_trigram: String, // Current trigram that must be contained in valid words
+    pub started_at: Timestamp, // When the game left the countdown
+    pub turn_started_at: Timestamp, // When the current turn began
     pub trigram_examples: Vec<TrigramExample>, // Last 3 trigrams and their example words
     pub winner: GameResult,    // Winner, Draw, or None
+    pub placements: Vec<PlayerPlacement>, // Final standings, filled in when the game ends
+    pub overtime_rounds: u32,  // Number of times a draw was sent to overtime
+    pub extra_time_seconds: u32, // Time added to the current turn by power-ups
+    pub next_trigram_hard: bool, // Draw the next trigram from the hard pool
+    pub rematch_votes: Vec<Identity>, // Players who want to play again once the game is over
+    pub reactions: Vec<Reaction>, // Most recent emote reactions (most recent last)
 }
 
 #[derive(Clone, Copy, SpacetimeType, PartialEq)]
 pub enum WinCondition {
     LastPlayerStanding { starting_lives: u32 },
     UseAllLetters,
+    Points { goal: PointsGoal },
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum PointsGoal {
+    TargetScore(u32), // First player to reach this score ends the game
+    Rounds(u32),      // Highest score after this many rounds wins
 }
 
 #[derive(Clone, SpacetimeType)]
@@ -111,6 +194,46 @@ pub struct SettingsState {
     pub win_condition: WinCondition,
     pub turn_logic_mode: TurnLogicMode,
     pub bonus_letter_word_count: Option<u32>,
+    pub max_rounds: Option<u32>, // End the game after this many rounds
+    pub match_time_limit_seconds: Option<u32>, // End the game after this much play time
+    pub limit_tiebreak: TiebreakRule, // Ranks players when a limit ends the game
+    pub overtime_enabled: bool,  // Revive the last players out instead of ending in a draw
+    pub power_ups_enabled: bool, // Award power-ups for word milestones
+    pub word_turn_effects: bool, // Palindromes reverse Classic turn order, long words skip a player
+    pub chess_clock: Option<ChessClockSettings>, // Per-player time banks instead of a per-turn fuse
+    pub countdown_seconds: u32,  // Countdown between starting the game and the first turn
+    pub ready_check_seconds: Option<u32>, // Players must confirm within this long or be dropped
+    pub series_best_of: Option<u32>, // Play a best-of-N series instead of single games
+    pub visibility: LobbyVisibility, // Whether the game is listed for anyone to join
+    pub max_players: u32,        // Seats in the game, later joiners wait as spectators
+    pub chat_blocks_trigram: bool, // Reject chat messages containing the trigram in play
+    pub content_filter: bool,    // Keep blocked words out of guesses and examples
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum LobbyVisibility {
+    Public,
+    Private,
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub struct ChessClockSettings {
+    pub bank_seconds: u32,      // Total time each player starts with
+    pub increment_seconds: u32, // Added to a player's bank for each accepted word
+    pub on_empty: ClockExpiry,
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum ClockExpiry {
+    Eliminate, // The player is out as soon as their bank runs dry
+    LoseLife,  // The player loses a life and their bank is refilled
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum TiebreakRule {
+    Lives,
+    LettersUsed,
+    WordsPlayed,
 }
 
 #[derive(Clone, SpacetimeType)]
@@ -119,6 +242,13 @@ pub struct CountdownState {
     pub settings: SettingsState,
 }
 
+#[derive(Clone, SpacetimeType)]
+pub struct ReadyCheckState {
+    pub settings: SettingsState,
+    pub ready_players: Vec<Identity>,
+    pub started_at: Timestamp,
+}
+
 #[derive(Clone, SpacetimeType)]
 pub struct InvalidGuessEvent {
     pub word: String,
@@ -140,6 +270,49 @@ pub enum GameStateEvent {
     CorrectGuess(String),
     LifeEarned,
     FreeLetterAward(FreeLetterAwardEvent),
+    Overtime,
+    PowerUpAwarded(PowerUp),
+    PowerUpUsed(PowerUpUsedEvent),
+    ShieldBlocked,
+    TurnOrderReversed,
+    PlayerSkipped(Identity),
+    BombPassedBack,
+    Reaction(Reaction),
+}
+
+#[derive(Clone, SpacetimeType)]
+pub struct PowerUpUsedEvent {
+    pub player_identity: Identity,
+    pub power_up: PowerUp,
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum Emote {
+    Laugh,
+    Wow,
+    Fire,
+    Clap,
+    Skull,
+}
+
+#[derive(Clone, SpacetimeType, PartialEq)]
+pub enum ReactionTarget {
+    Player(Identity),
+    Guess(ReactedGuess),
+}
+
+#[derive(Clone, SpacetimeType, PartialEq)]
+pub struct ReactedGuess {
+    pub player_identity: Identity, // Player who made the guess
+    pub word: String,              // Word from their past guesses
+}
+
+#[derive(Clone, SpacetimeType)]
+pub struct Reaction {
+    pub from: Identity,
+    pub emote: Emote,
+    pub target: ReactionTarget,
+    pub reacted_at: Timestamp,
 }
 
 #[spacetimedb::table(name = game_state, public)]
@@ -150,6 +323,16 @@ pub struct GameStateTable {
     pub state: GameState,
     pub updated_at: Timestamp,
     pub player_wins: Vec<PlayerWins>, // Track number of wins per player in this game
+    pub series: Option<SeriesState>,  // The series being played, if the game is set up for one
+    pub spectators: Vec<Identity>,    // Joiners waiting for a seat, first in line first
+}
+
+#[derive(Clone, SpacetimeType)]
+pub struct SeriesState {
+    pub best_of: u32,
+    pub wins: Vec<PlayerWins>, // Wins per player within this series
+    pub games_played: u32,
+    pub winner: Option<Identity>,
 }
 
 #[spacetimedb::table(name = game, public)]
@@ -162,6 +345,7 @@ pub struct Game {
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
     pub player_identities: Vec<Identity>, // Track connected players
+    pub ranked: bool,                     // Created by the ranked queue, so results are rated
 }
 
 #[spacetimedb::table(name = turn_timeout_schedule, scheduled(turn_timeout))]
@@ -176,36 +360,143 @@ struct TurnTimeoutSchedule {
 
 // Helper function to schedule a turn timeout
 fn schedule_turn_timeout(ctx: &ReducerContext, state: &PlayingState, game_id: u32) {
-    let timeout_micros = (state.settings.turn_timeout_seconds as i64) * 1_000_000;
+    match (active_chess_clock(&state.settings), &state.turn_logic) {
+        (Some(_), TurnLogic::Classic(classic)) => {
+            if let Some(player) = state.players.get(classic.current_turn_index as usize) {
+                schedule_turn_timeout_after(ctx, player.time_bank, state.turn_number, game_id);
+                return;
+            }
+        }
+        (Some(_), TurnLogic::Simultaneous(_)) => {}
+        (None, _) => {}
+    }
+    // Simultaneous turns share one timer, so only Classic turns get a player's extra seconds
+    let extra_seconds = match &state.turn_logic {
+        TurnLogic::Classic(classic) => state
+            .players
+            .get(classic.current_turn_index as usize)
+            .map(|player| player.handicap.extra_seconds)
+            .unwrap_or(0),
+        TurnLogic::Simultaneous(_) => 0,
+    };
+    schedule_turn_timeout_after(
+        ctx,
+        seconds(state.settings.turn_timeout_seconds + extra_seconds),
+        state.turn_number,
+        game_id,
+    );
+}
+
+// Helper function to convert whole seconds into a TimeDuration
+fn seconds(seconds: u32) -> TimeDuration {
+    TimeDuration::from_micros(seconds as i64 * 1_000_000)
+}
+
+// Helper function to schedule a timeout for a turn after the given duration
+fn schedule_turn_timeout_after(
+    ctx: &ReducerContext,
+    duration: TimeDuration,
+    turn_number: u32,
+    game_id: u32,
+) {
     let timeout = TurnTimeoutSchedule {
         scheduled_id: 0, // Auto-incremented
-        scheduled_at: (ctx.timestamp + TimeDuration::from_micros(timeout_micros)).into(),
-        turn_number: state.turn_number,
+        scheduled_at: (ctx.timestamp + duration).into(),
+        turn_number,
         game_id,
     };
     ctx.db.turn_timeout_schedule().insert(timeout);
 }
 
+// Helper function to stamp the start of a new turn and schedule its timeout
+fn begin_turn(ctx: &ReducerContext, state: &mut PlayingState, game_id: u32) {
+    state.turn_started_at = ctx.timestamp;
+    state.extra_time_seconds = 0;
+    schedule_turn_timeout(ctx, state, game_id);
+}
+
 #[spacetimedb::reducer]
 fn turn_timeout(ctx: &ReducerContext, arg: TurnTimeoutSchedule) -> Result<(), String> {
     // Protect against stale timeouts
-    match get_game_state(ctx, arg.game_id) {
-        Some(game_state) => match game_state.state {
+    if let Some(mut game_state) = get_game_state(ctx, arg.game_id) {
+        match &mut game_state.state {
             GameState::Settings(_) => {}
+            GameState::ReadyCheck(_) => {}
             GameState::Countdown(_) => {}
             GameState::Playing(playing_state) => {
                 if playing_state.turn_number != arg.turn_number {
                     // This isn't really an error it just means that the timeout is for a stale turn
                     return Ok(());
                 }
+                // Extra time from power-ups pushes the fuse back instead of ending the turn
+                if playing_state.extra_time_seconds > 0 {
+                    schedule_turn_timeout_after(
+                        ctx,
+                        seconds(playing_state.extra_time_seconds),
+                        playing_state.turn_number,
+                        arg.game_id,
+                    );
+                    playing_state.extra_time_seconds = 0;
+                    // Nothing happened this turn, so don't replay the events from the last one
+                    for player in &mut playing_state.players {
+                        player.events.clear();
+                    }
+                    update_game_state(ctx, game_state);
+                    return Ok(());
+                }
             }
-        },
-        None => {}
+        }
     }
 
     update_game_state_and_schedule_turn_timeout(ctx, arg.game_id, Move::TimeUp)
 }
 
+#[spacetimedb::table(name = match_clock_schedule, scheduled(match_clock_timeout))]
+struct MatchClockSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    game_id: u32,
+    started_at: Timestamp, // Identifies which game of this room the clock belongs to
+}
+
+// Helper function to schedule the end of a time-limited game
+fn schedule_match_clock(ctx: &ReducerContext, seconds: u32, game_id: u32, started_at: Timestamp) {
+    let timeout_micros = (seconds as i64) * 1_000_000;
+    let clock = MatchClockSchedule {
+        scheduled_id: 0, // Auto-incremented
+        scheduled_at: (ctx.timestamp + TimeDuration::from_micros(timeout_micros)).into(),
+        game_id,
+        started_at,
+    };
+    ctx.db.match_clock_schedule().insert(clock);
+}
+
+#[spacetimedb::reducer]
+fn match_clock_timeout(ctx: &ReducerContext, arg: MatchClockSchedule) -> Result<(), String> {
+    // Protect against clocks from earlier games or games that already ended
+    match get_game_state(ctx, arg.game_id) {
+        Some(game_state) => match game_state.state {
+            GameState::Settings(_) => return Ok(()),
+            GameState::ReadyCheck(_) => return Ok(()),
+            GameState::Countdown(_) => return Ok(()),
+            GameState::Playing(playing_state) => {
+                if playing_state.started_at != arg.started_at {
+                    return Ok(());
+                }
+                match playing_state.winner {
+                    GameResult::None => {}
+                    GameResult::Winner(_) | GameResult::Draw => return Ok(()),
+                }
+            }
+        },
+        None => return Ok(()),
+    }
+
+    update_game_state_and_schedule_turn_timeout(ctx, arg.game_id, Move::MatchTimeUp)
+}
+
 #[spacetimedb::table(name = game_countdown_schedule, scheduled(game_countdown))]
 pub struct GameCountdownSchedule {
     #[primary_key]
@@ -228,6 +519,216 @@ fn schedule_game_start(ctx: &ReducerContext, countdown_seconds: u32, game_id: u3
     ctx.db.game_countdown_schedule().insert(countdown);
 }
 
+// Helper function to move a game into the countdown before its first turn
+fn begin_countdown(ctx: &ReducerContext, game_state: &mut GameStateTable, settings: SettingsState) {
+    let countdown_seconds = settings.countdown_seconds;
+    schedule_game_start(ctx, countdown_seconds, game_state.game_id);
+    game_state.state = GameState::Countdown(CountdownState {
+        countdown_seconds,
+        settings,
+    });
+}
+
+#[spacetimedb::table(name = ready_check_schedule, scheduled(ready_check_timeout))]
+pub struct ReadyCheckSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    game_id: u32,
+    started_at: Timestamp, // Identifies which ready check of this room the timeout belongs to
+}
+
+// Helper function to schedule the end of a ready check
+fn schedule_ready_check_timeout(ctx: &ReducerContext, seconds: u32, game_id: u32) {
+    let timeout_micros = (seconds as i64) * 1_000_000;
+    ctx.db.ready_check_schedule().insert(ReadyCheckSchedule {
+        scheduled_id: 0, // Auto-incremented
+        scheduled_at: (ctx.timestamp + TimeDuration::from_micros(timeout_micros)).into(),
+        game_id,
+        started_at: ctx.timestamp,
+    });
+}
+
+#[spacetimedb::reducer]
+pub fn confirm_ready(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
+    if let Some(mut game_state) = get_game_state(ctx, game_id) {
+        match &mut game_state.state {
+            GameState::ReadyCheck(ready_check) => {
+                if !ready_check
+                    .settings
+                    .players
+                    .iter()
+                    .any(|p| p.player_identity == ctx.sender)
+                {
+                    return Err("Player not in this game".to_string());
+                }
+                if !ready_check.ready_players.contains(&ctx.sender) {
+                    ready_check.ready_players.push(ctx.sender);
+                }
+                let all_ready = ready_check
+                    .settings
+                    .players
+                    .iter()
+                    .all(|p| ready_check.ready_players.contains(&p.player_identity));
+                if all_ready {
+                    let settings = ready_check.settings.clone();
+                    begin_countdown(ctx, &mut game_state, settings);
+                }
+                update_game_state(ctx, game_state);
+                Ok(())
+            }
+            GameState::Settings(_) => Err("No ready check in progress".to_string()),
+            GameState::Countdown(_) => Err("Game is already in countdown".to_string()),
+            GameState::Playing(_) => Err("Game already in progress".to_string()),
+        }
+    } else {
+        Err("Game not initialized".to_string())
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn ready_check_timeout(ctx: &ReducerContext, arg: ReadyCheckSchedule) -> Result<(), String> {
+    if let Some(mut game_state) = get_game_state(ctx, arg.game_id) {
+        let ready_check = match &game_state.state {
+            GameState::ReadyCheck(ready_check) => ready_check.clone(),
+            // Everyone confirmed or the start was cancelled before the timeout fired
+            GameState::Settings(_) => return Ok(()),
+            GameState::Countdown(_) => return Ok(()),
+            GameState::Playing(_) => return Ok(()),
+        };
+        // Protect against timeouts from an earlier ready check
+        if ready_check.started_at != arg.started_at {
+            return Ok(());
+        }
+        let (ready_players, unready_players): (Vec<_>, Vec<_>) = ready_check
+            .settings
+            .players
+            .into_iter()
+            .partition(|p| ready_check.ready_players.contains(&p.player_identity));
+        for player in &unready_players {
+            remove_identity_from_game_list(ctx, arg.game_id, player.player_identity);
+        }
+        let settings = SettingsState {
+            players: ready_players,
+            ..ready_check.settings
+        };
+        // Go back to the lobby if too few players confirmed
+        if settings.players.len() >= 2 {
+            begin_countdown(ctx, &mut game_state, settings);
+        } else {
+            game_state.state = GameState::Settings(settings);
+            fill_open_seats(&mut game_state);
+        }
+        update_game_state(ctx, game_state);
+    }
+    Ok(())
+}
+
+// Helper function to send a game that is starting back to Settings
+fn cancel_game_start(ctx: &ReducerContext, game_state: &mut GameStateTable) -> Result<(), String> {
+    let settings = settings_before_start(&game_state.state)?;
+    delete_start_schedules(ctx, game_state.game_id);
+    game_state.state = GameState::Settings(settings);
+    Ok(())
+}
+
+// Helper function to get the settings a starting game goes back to when it is cancelled
+fn settings_before_start(state: &GameState) -> Result<SettingsState, String> {
+    match state {
+        GameState::ReadyCheck(ready_check) => Ok(ready_check.settings.clone()),
+        GameState::Countdown(countdown_state) => Ok(countdown_state.settings.clone()),
+        GameState::Settings(_) => Err("Game is not starting".to_string()),
+        GameState::Playing(_) => Err("Game already in progress".to_string()),
+    }
+}
+
+// Helper function to drop a game's pending countdown and ready check timeouts
+fn delete_start_schedules(ctx: &ReducerContext, game_id: u32) {
+    let countdowns: Vec<u64> = ctx
+        .db
+        .game_countdown_schedule()
+        .iter()
+        .filter(|countdown| countdown.game_id == game_id)
+        .map(|countdown| countdown.scheduled_id)
+        .collect();
+    for scheduled_id in countdowns {
+        ctx.db
+            .game_countdown_schedule()
+            .scheduled_id()
+            .delete(scheduled_id);
+    }
+    let ready_checks: Vec<u64> = ctx
+        .db
+        .ready_check_schedule()
+        .iter()
+        .filter(|ready_check| ready_check.game_id == game_id)
+        .map(|ready_check| ready_check.scheduled_id)
+        .collect();
+    for scheduled_id in ready_checks {
+        ctx.db
+            .ready_check_schedule()
+            .scheduled_id()
+            .delete(scheduled_id);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn cancel_countdown(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
+    if let Some(mut game_state) = get_game_state(ctx, game_id) {
+        cancel_game_start(ctx, &mut game_state)?;
+        update_game_state(ctx, game_state);
+        Ok(())
+    } else {
+        Err("Game not initialized".to_string())
+    }
+}
+
+// Helper function to cancel any starting game the disconnected player was in that no
+// longer has two connected players
+fn cancel_starts_without_enough_players(ctx: &ReducerContext, identity: Identity) {
+    let starting_games: Vec<GameStateTable> = ctx
+        .db
+        .game_state()
+        .iter()
+        .filter(|game_state| {
+            start_lacks_players(&game_state.state, identity, |player| {
+                ctx.db
+                    .player_info()
+                    .identity()
+                    .find(player)
+                    .is_some_and(|info| info.is_online)
+            })
+        })
+        .collect();
+    for mut game_state in starting_games {
+        if cancel_game_start(ctx, &mut game_state).is_ok() {
+            update_game_state(ctx, game_state);
+        }
+    }
+}
+
+// Helper function to check whether a starting game the player was in is down to fewer than
+// two connected players
+fn start_lacks_players(
+    state: &GameState,
+    identity: Identity,
+    is_online: impl Fn(Identity) -> bool,
+) -> bool {
+    let players = match state {
+        GameState::ReadyCheck(ready_check) => &ready_check.settings.players,
+        GameState::Countdown(countdown_state) => &countdown_state.settings.players,
+        GameState::Settings(_) => return false,
+        GameState::Playing(_) => return false,
+    };
+    players.iter().any(|p| p.player_identity == identity)
+        && players
+            .iter()
+            .filter(|p| is_online(p.player_identity))
+            .count()
+            < 2
+}
+
 #[spacetimedb::reducer]
 pub fn game_countdown(ctx: &ReducerContext, arg: GameCountdownSchedule) -> Result<(), String> {
     if let Some(mut game_state) = get_game_state(ctx, arg.game_id) {
@@ -244,16 +745,31 @@ pub fn game_countdown(ctx: &ReducerContext, arg: GameCountdownSchedule) -> Resul
                 }
 
                 // Create shuffled players vector
-                let shuffled_players: Vec<PlayerGameData> = player_indices
+                let mut shuffled_players: Vec<PlayerGameData> = player_indices
                     .iter()
                     .map(|&i| settings_clone.players[i].clone())
                     .collect();
 
+                // Hand out handicap free letters and fill chess clocks
+                let chess_clock = active_chess_clock(&settings_clone);
+                for player in &mut shuffled_players {
+                    // Nothing from the previous game (votes, reactions) carries into this one
+                    player.events.clear();
+                    if let Some(clock) = chess_clock {
+                        player.time_bank = full_time_bank(player, clock);
+                    }
+                    for _ in 0..player.handicap.starting_free_letters {
+                        award_random_free_letter(player, &mut ctx.rng());
+                    }
+                }
+
                 // Select turn logic based on settings
                 let turn_logic = match settings_clone.turn_logic_mode {
                     TurnLogicMode::Classic => TurnLogic::Classic(ClassicTurnLogic {
                         current_turn_index: 0,
                         failed_players: Vec::new(),
+                        direction: TurnDirection::Forward,
+                        pending_effect: None,
                     }),
                     TurnLogicMode::Simultaneous => {
                         TurnLogic::Simultaneous(SimultaneousTurnLogic {})
@@ -265,27 +781,35 @@ pub fn game_countdown(ctx: &ReducerContext, arg: GameCountdownSchedule) -> Resul
                     turn_logic,
                     turn_number: 0,
                     settings: SettingsState {
-                        turn_timeout_seconds: settings_clone.turn_timeout_seconds,
                         players: Vec::new(),
-                        win_condition: settings_clone.win_condition,
-                        turn_logic_mode: settings_clone.turn_logic_mode,
-                        bonus_letter_word_count: settings_clone.bonus_letter_word_count,
+                        ..settings_clone
                     },
                     current_trigram: String::new(),
+                    started_at: ctx.timestamp,
+                    turn_started_at: ctx.timestamp,
                     trigram_examples: Vec::new(),
                     winner: GameResult::None,
+                    placements: Vec::new(),
+                    overtime_rounds: 0,
+                    extra_time_seconds: 0,
+                    next_trigram_hard: false,
+                    rematch_votes: Vec::new(),
+                    reactions: Vec::new(),
                 };
 
                 // Pick initial random trigram
-                pick_random_trigram_and_update(&mut playing_state, &mut ctx.rng());
+                pick_random_trigram_and_update(
+                    &mut playing_state,
+                    &|word| content_filter::is_blocked(ctx, word),
+                    &mut ctx.rng(),
+                );
 
                 // Classic: Emit MyTurn event to the first player in shuffled order
                 match &playing_state.turn_logic {
                     TurnLogic::Classic(_) => {
-                        playing_state
-                            .players
-                            .get_mut(0)
-                            .map(|player| player.events.push(GameStateEvent::MyTurn));
+                        if let Some(player) = playing_state.players.get_mut(0) {
+                            player.events.push(GameStateEvent::MyTurn)
+                        }
                     }
                     TurnLogic::Simultaneous(_) => {
                         playing_state.players.iter_mut().for_each(|player| {
@@ -295,7 +819,11 @@ pub fn game_countdown(ctx: &ReducerContext, arg: GameCountdownSchedule) -> Resul
                 }
 
                 // Schedule the first turn timeout
-                schedule_turn_timeout(ctx, &playing_state, arg.game_id);
+                begin_turn(ctx, &mut playing_state, arg.game_id);
+
+                if let Some(seconds) = playing_state.settings.match_time_limit_seconds {
+                    schedule_match_clock(ctx, seconds, arg.game_id, playing_state.started_at);
+                }
 
                 game_state.state = GameState::Playing(playing_state);
                 game_state.updated_at = ctx.timestamp;
@@ -310,30 +838,123 @@ pub fn game_countdown(ctx: &ReducerContext, arg: GameCountdownSchedule) -> Resul
     }
 }
 
-#[derive(SpacetimeType)]
-struct TimeUpMove {}
-
-#[derive(SpacetimeType)]
-struct EndTurnMove {}
-
-#[derive(SpacetimeType)]
-struct SubmitWordMove {
-    word: String,
-}
-
 #[derive(Clone, SpacetimeType)]
 pub struct GuessWordMove {
     pub player_identity: Identity,
     pub word: String,
+    pub guessed_at: Timestamp,
+}
+
+#[derive(Clone, SpacetimeType)]
+pub struct UsePowerUpMove {
+    pub player_identity: Identity,
+    pub power_up: PowerUp,
 }
 
 #[derive(Clone, SpacetimeType)]
 pub enum Move {
     TimeUp,
     GuessWord(GuessWordMove),
+    MatchTimeUp,
+    UsePowerUp(UsePowerUpMove),
+}
+
+// Helper function to read a player's score in Points mode
+fn score(player: &PlayerGameData) -> u32 {
+    match player.win_condition_data {
+        PlayerWinConditionData::LastPlayerStanding { .. } => 0,
+        PlayerWinConditionData::UseAllLetters => 0,
+        PlayerWinConditionData::Points { score } => score,
+    }
+}
+
+// Helper function to declare the highest scorer the winner, or a draw if the lead is shared
+fn highest_score_result(state: &PlayingState) -> GameResult {
+    let best = state.players.iter().map(score).max().unwrap_or(0);
+    let leaders: Vec<_> = state.players.iter().filter(|p| score(p) == best).collect();
+    match leaders.len() {
+        1 => GameResult::Winner(leaders[0].player_identity),
+        _ => GameResult::Draw,
+    }
+}
+
+// Whether the current turn has finished when checking for a winner
+#[derive(Clone, Copy)]
+pub enum TurnPhase {
+    TurnInProgress,
+    TurnComplete,
+}
+
+// Helper function to count the turns that have been played to completion
+fn turns_finished(state: &PlayingState, phase: TurnPhase) -> u32 {
+    match phase {
+        TurnPhase::TurnInProgress => state.turn_number,
+        TurnPhase::TurnComplete => state.turn_number + 1,
+    }
+}
+
+// Helper function to check whether a number of full rounds has been played. A Classic
+// round is one turn for each player still in the game, so the game only stops once every
+// one of them has had that many turns, however skips and eliminations reorder play.
+// Simultaneous players all answer every turn.
+fn rounds_reached(state: &PlayingState, phase: TurnPhase, rounds: u32) -> bool {
+    match state.turn_logic {
+        TurnLogic::Classic(_) => state
+            .players
+            .iter()
+            .filter(|p| is_active(p))
+            .map(|p| p.turns_taken)
+            .min()
+            .is_some_and(|turns| turns >= rounds),
+        TurnLogic::Simultaneous(_) => turns_finished(state, phase) >= rounds,
+    }
+}
+
+// Helper function to measure a player by the configured tiebreak rule
+fn tiebreak_value(player: &PlayerGameData, rule: TiebreakRule) -> u32 {
+    match rule {
+        TiebreakRule::Lives => match player.win_condition_data {
+            PlayerWinConditionData::LastPlayerStanding { lives } => lives.max(0) as u32,
+            PlayerWinConditionData::UseAllLetters => 0,
+            PlayerWinConditionData::Points { .. } => 0,
+        },
+        TiebreakRule::LettersUsed => letters_used(player),
+        TiebreakRule::WordsPlayed => player.past_guesses.len() as u32,
+    }
+}
+
+// Helper function to pick a result when a round or time limit stops the game. Players still
+// in the game are ranked by score (Points only) and then by the tiebreak rule.
+fn limit_result(state: &PlayingState) -> GameResult {
+    let contenders: Vec<&PlayerGameData> = state.players.iter().filter(|p| is_active(p)).collect();
+    let standing =
+        |p: &PlayerGameData| (score(p), tiebreak_value(p, state.settings.limit_tiebreak));
+    let best = contenders.iter().map(|p| standing(p)).max();
+    let leaders: Vec<_> = contenders
+        .iter()
+        .filter(|p| Some(standing(p)) == best)
+        .collect();
+    match leaders.len() {
+        1 => GameResult::Winner(leaders[0].player_identity),
+        _ => GameResult::Draw,
+    }
+}
+
+fn has_winner(state: &PlayingState, phase: TurnPhase) -> GameResult {
+    match win_condition_result(state, phase) {
+        GameResult::None => match (phase, state.settings.max_rounds) {
+            (TurnPhase::TurnComplete, Some(max_rounds))
+                if rounds_reached(state, phase, max_rounds) =>
+            {
+                limit_result(state)
+            }
+            _ => GameResult::None,
+        },
+        result => result,
+    }
 }
 
-fn has_winner(state: &PlayingState) -> GameResult {
+fn win_condition_result(state: &PlayingState, phase: TurnPhase) -> GameResult {
     match state.settings.win_condition {
         WinCondition::LastPlayerStanding { .. } => {
             let players_with_lives: Vec<_> = state
@@ -342,6 +963,7 @@ fn has_winner(state: &PlayingState) -> GameResult {
                 .filter(|p| match p.win_condition_data {
                     PlayerWinConditionData::LastPlayerStanding { lives } => lives > 0,
                     PlayerWinConditionData::UseAllLetters => false,
+                    PlayerWinConditionData::Points { .. } => false,
                 })
                 .collect();
             match players_with_lives.len() {
@@ -362,51 +984,389 @@ fn has_winner(state: &PlayingState) -> GameResult {
                 GameResult::None
             }
         }
+        WinCondition::Points { goal } => {
+            let goal_reached = match goal {
+                PointsGoal::TargetScore(target) => state.players.iter().any(|p| score(p) >= target),
+                PointsGoal::Rounds(rounds) => rounds_reached(state, phase, rounds),
+            };
+            if goal_reached {
+                highest_score_result(state)
+            } else {
+                GameResult::None
+            }
+        }
     }
 }
 
-pub enum ShouldScheduleTurnTimeout {
-    ScheduleTurnTimeout,
-    DoNotScheduleTurnTimeout,
+// Helper function to count the distinct letters a player has used or been awarded
+fn letters_used(player: &PlayerGameData) -> u32 {
+    ('A'..='Z')
+        .filter(|c| {
+            let letter = c.to_string();
+            player.used_letters.contains(&letter) || player.free_letters.contains(&letter)
+        })
+        .count() as u32
 }
 
-// Helper function to handle end of turn logic
-fn end_turn(
-    game_state: &mut GameStateTable,
-    rng: &mut impl rand::RngCore,
-) -> ShouldScheduleTurnTimeout {
-    match &mut game_state.state {
-        GameState::Settings(_) => {
-            return ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout;
-        }
-        GameState::Countdown(_) => {
-            return ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout;
-        }
-        GameState::Playing(state) => {
-            let result = has_winner(state);
-            match result {
-                GameResult::None => {
-                    match &mut state.turn_logic {
-                        TurnLogic::Classic(classic) => {
-                            let (next_player, next_player_index) = state
-                                .players
-                                .iter()
-                                .zip(0..)
-                                .cycle()
-                                .skip(classic.current_turn_index as usize + 1)
-                                .find(|(p, _)| match p.win_condition_data {
-                                    PlayerWinConditionData::LastPlayerStanding { lives } => {
-                                        lives > 0
-                                    }
-                                    PlayerWinConditionData::UseAllLetters => true,
-                                })
-                                .unwrap();
-                            let next_player_identity = next_player.player_identity;
-                            state
+// Helper function to rank every player once the game is over. The winner comes first,
+// then LastPlayerStanding orders by how late players were eliminated, UseAllLetters by
+// how many letters they got through and Points by score, with the tiebreak rule deciding
+// anything left level. Players with the same standing share a placement.
+fn compute_placements(state: &PlayingState) -> Vec<PlayerPlacement> {
+    let standing = |player: &PlayerGameData| {
+        let is_winner = match state.winner {
+            GameResult::Winner(winner) => winner == player.player_identity,
+            GameResult::Draw => false,
+            GameResult::None => false,
+        };
+        let progress = match player.win_condition_data {
+            PlayerWinConditionData::LastPlayerStanding { .. } => {
+                player.eliminated_turn.unwrap_or(u32::MAX)
+            }
+            PlayerWinConditionData::UseAllLetters => letters_used(player),
+            PlayerWinConditionData::Points { score } => score,
+        };
+        (
+            is_winner,
+            progress,
+            tiebreak_value(player, state.settings.limit_tiebreak),
+        )
+    };
+    state
+        .players
+        .iter()
+        .map(|player| {
+            let player_standing = standing(player);
+            let players_ahead = state
+                .players
+                .iter()
+                .filter(|other| standing(other) > player_standing)
+                .count() as u32;
+            PlayerPlacement {
+                player_identity: player.player_identity,
+                placement: players_ahead + 1,
+                eliminated_turn: player.eliminated_turn,
+                letters_used: letters_used(player),
+            }
+        })
+        .collect()
+}
+
+// Helper function to record the result, win counts, placements and win/lose events
+fn finish_game(state: &mut PlayingState, player_wins: &mut Vec<PlayerWins>, result: GameResult) {
+    match result {
+        GameResult::Winner(winner) => {
+            match player_wins.iter_mut().find(|w| w.player_identity == winner) {
+                Some(wins) => {
+                    wins.wins += 1;
+                }
+                None => {
+                    player_wins.push(PlayerWins {
+                        player_identity: winner,
+                        wins: 1,
+                    });
+                }
+            }
+            state.players.iter_mut().for_each(|player| {
+                player.events.push(if player.player_identity == winner {
+                    GameStateEvent::IWin
+                } else {
+                    GameStateEvent::ILose
+                })
+            });
+        }
+        GameResult::Draw => {
+            state.players.iter_mut().for_each(|player| {
+                player.events.push(GameStateEvent::ILose);
+            });
+        }
+        GameResult::None => {}
+    }
+    state.winner = result;
+    state.placements = compute_placements(state);
+}
+
+// Helper function to refresh a player's fastest and average answer times from their guesses
+fn update_answer_times(player: &mut PlayerGameData) {
+    let answer_micros: Vec<i64> = player
+        .past_guesses
+        .iter()
+        .map(|guess| {
+            guess.guessed_at.to_micros_since_unix_epoch()
+                - guess.turn_started_at.to_micros_since_unix_epoch()
+        })
+        .collect();
+    player.fastest_answer = answer_micros
+        .iter()
+        .min()
+        .map(|micros| TimeDuration::from_micros(*micros));
+    player.average_answer = match answer_micros.len() {
+        0 => None,
+        count => Some(TimeDuration::from_micros(
+            answer_micros.iter().sum::<i64>() / count as i64,
+        )),
+    };
+}
+
+// The chess clock only runs in Classic LastPlayerStanding games, where running out of
+// time has a penalty and there is a single player on the clock
+fn active_chess_clock(settings: &SettingsState) -> Option<ChessClockSettings> {
+    match (
+        settings.chess_clock,
+        settings.turn_logic_mode,
+        settings.win_condition,
+    ) {
+        (Some(clock), TurnLogicMode::Classic, WinCondition::LastPlayerStanding { .. }) => {
+            Some(clock)
+        }
+        _ => None,
+    }
+}
+
+// Helper function to get a player's full chess clock bank, including their handicap
+fn full_time_bank(player: &PlayerGameData, clock: ChessClockSettings) -> TimeDuration {
+    seconds(clock.bank_seconds + player.handicap.extra_seconds)
+}
+
+// Helper function to take a turn's thinking time off a player's bank and add the increment
+fn spend_clock_time(
+    player: &mut PlayerGameData,
+    thinking_time: TimeDuration,
+    clock: ChessClockSettings,
+) {
+    let remaining = (player.time_bank.to_micros() - thinking_time.to_micros().max(0)).max(0);
+    player.time_bank = TimeDuration::from_micros(remaining) + seconds(clock.increment_seconds);
+}
+
+// Helper function to apply the chess clock penalty to a player whose bank ran out
+fn run_out_of_time(player: &mut PlayerGameData, clock: ChessClockSettings, turn_number: u32) {
+    match clock.on_empty {
+        ClockExpiry::Eliminate => {
+            match player.win_condition_data {
+                PlayerWinConditionData::LastPlayerStanding { lives } => {
+                    player.win_condition_data =
+                        PlayerWinConditionData::LastPlayerStanding { lives: 0 };
+                    if lives > 0 {
+                        player.eliminated_turn = Some(turn_number);
+                    }
+                }
+                PlayerWinConditionData::UseAllLetters => {}
+                PlayerWinConditionData::Points { .. } => {}
+            }
+            player.time_bank = TimeDuration::from_micros(0);
+        }
+        ClockExpiry::LoseLife => {
+            lose_life(player, turn_number);
+            player.time_bank = full_time_bank(player, clock);
+        }
+    }
+}
+
+// Helper function to take a life in LastPlayerStanding, noting the turn the player went out
+fn lose_life(player: &mut PlayerGameData, turn_number: u32) {
+    match player.win_condition_data {
+        PlayerWinConditionData::LastPlayerStanding { lives } => {
+            if player.shield_active {
+                player.shield_active = false;
+                player.events.push(GameStateEvent::ShieldBlocked);
+                return;
+            }
+            let new_lives = (lives - 1).max(0);
+            player.win_condition_data =
+                PlayerWinConditionData::LastPlayerStanding { lives: new_lives };
+            if lives > 0 && new_lives == 0 {
+                player.eliminated_turn = Some(turn_number);
+            }
+        }
+        PlayerWinConditionData::UseAllLetters => {}
+        PlayerWinConditionData::Points { .. } => {}
+    }
+}
+
+pub enum ShouldScheduleTurnTimeout {
+    ScheduleTurnTimeout,
+    DoNotScheduleTurnTimeout,
+}
+
+// Overtime only settles LastPlayerStanding draws where every player ran out of lives
+fn should_start_overtime(state: &PlayingState) -> bool {
+    match state.settings.win_condition {
+        WinCondition::LastPlayerStanding { .. } => {
+            state.settings.overtime_enabled
+                && state.players.iter().any(|p| p.eliminated_turn.is_some())
+                && state.players.iter().all(|p| match p.win_condition_data {
+                    PlayerWinConditionData::LastPlayerStanding { lives } => lives <= 0,
+                    PlayerWinConditionData::UseAllLetters => true,
+                    PlayerWinConditionData::Points { .. } => true,
+                })
+        }
+        WinCondition::UseAllLetters => false,
+        WinCondition::Points { .. } => false,
+    }
+}
+
+// Helper function to revive the players eliminated last with one life and switch the
+// game onto hard trigrams
+fn start_overtime(state: &mut PlayingState, rng: &mut impl rand::RngCore) {
+    revive_last_eliminated(state);
+    // The trigram for the next turn has already been drawn, so swap it for a hard one
+    // rather than storing an example for a trigram nobody played
+    state.current_trigram = pick_random_trigram(state, rng);
+}
+
+// Helper function to bring the players eliminated last back with one life (and a full
+// chess clock) and count another overtime round
+fn revive_last_eliminated(state: &mut PlayingState) {
+    let last_elimination = state.players.iter().filter_map(|p| p.eliminated_turn).max();
+    state
+        .players
+        .iter_mut()
+        .filter(|p| p.eliminated_turn.is_some() && p.eliminated_turn == last_elimination)
+        .for_each(|player| {
+            player.win_condition_data = PlayerWinConditionData::LastPlayerStanding { lives: 1 };
+            player.eliminated_turn = None;
+        });
+    if let Some(clock) = active_chess_clock(&state.settings) {
+        state
+            .players
+            .iter_mut()
+            .filter(|p| is_active(p))
+            .for_each(|player| player.time_bank = full_time_bank(player, clock));
+    }
+    state.players.iter_mut().for_each(|player| {
+        player.events.push(GameStateEvent::Overtime);
+    });
+    state.overtime_rounds += 1;
+}
+
+// Helper function to check whether a player can still take turns
+fn is_active(player: &PlayerGameData) -> bool {
+    match player.win_condition_data {
+        PlayerWinConditionData::LastPlayerStanding { lives } => lives > 0,
+        PlayerWinConditionData::UseAllLetters => true,
+        PlayerWinConditionData::Points { .. } => true,
+    }
+}
+
+// Helper function to find the next player still in the game, walking in the turn direction
+fn next_active_player_index(
+    players: &[PlayerGameData],
+    current_turn_index: u32,
+    direction: TurnDirection,
+) -> u32 {
+    let player_count = players.len() as u32;
+    (1..=player_count)
+        .map(|step| match direction {
+            TurnDirection::Forward => (current_turn_index + step) % player_count,
+            TurnDirection::Backward => {
+                (current_turn_index + player_count - step % player_count) % player_count
+            }
+        })
+        .find(|index| is_active(&players[*index as usize]))
+        .unwrap_or(current_turn_index)
+}
+
+// Helper function to flip a turn direction
+fn reversed(direction: TurnDirection) -> TurnDirection {
+    match direction {
+        TurnDirection::Forward => TurnDirection::Backward,
+        TurnDirection::Backward => TurnDirection::Forward,
+    }
+}
+
+const SKIP_WORD_LENGTH: usize = 12; // Words at least this long skip the next player
+
+// Helper function to apply the word rules that change Classic turn order
+fn apply_word_turn_effects(
+    classic: &mut ClassicTurnLogic,
+    word: &str,
+    players: &mut [PlayerGameData],
+) {
+    let is_palindrome = word.len() >= 3 && word.chars().eq(word.chars().rev());
+    if is_palindrome {
+        classic.direction = reversed(classic.direction);
+        players
+            .iter_mut()
+            .for_each(|player| player.events.push(GameStateEvent::TurnOrderReversed));
+    }
+    if word.len() >= SKIP_WORD_LENGTH {
+        classic.pending_effect = Some(TurnEffect::SkipNextPlayer);
+    }
+}
+
+// Helper function to handle end of turn logic
+fn end_turn(
+    game_state: &mut GameStateTable,
+    is_blocked: &dyn Fn(&str) -> bool,
+    rng: &mut impl rand::RngCore,
+) -> ShouldScheduleTurnTimeout {
+    match &mut game_state.state {
+        GameState::Settings(_) => ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout,
+        GameState::ReadyCheck(_) => ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout,
+        GameState::Countdown(_) => ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout,
+        GameState::Playing(state) => {
+            match &state.turn_logic {
+                TurnLogic::Classic(classic) => {
+                    state.players[classic.current_turn_index as usize].turns_taken += 1;
+                }
+                TurnLogic::Simultaneous(_) => {}
+            }
+            let result = match has_winner(state, TurnPhase::TurnComplete) {
+                GameResult::Draw if should_start_overtime(state) => {
+                    start_overtime(state, rng);
+                    GameResult::None
+                }
+                result => result,
+            };
+            match result {
+                GameResult::None => {
+                    match &mut state.turn_logic {
+                        TurnLogic::Classic(classic) => {
+                            let next_player_index = match classic.pending_effect.take() {
+                                None => next_active_player_index(
+                                    &state.players,
+                                    classic.current_turn_index,
+                                    classic.direction,
+                                ),
+                                Some(TurnEffect::SkipNextPlayer) => {
+                                    let skipped_index = next_active_player_index(
+                                        &state.players,
+                                        classic.current_turn_index,
+                                        classic.direction,
+                                    );
+                                    let skipped_identity =
+                                        state.players[skipped_index as usize].player_identity;
+                                    state.players.iter_mut().for_each(|player| {
+                                        player
+                                            .events
+                                            .push(GameStateEvent::PlayerSkipped(skipped_identity))
+                                    });
+                                    next_active_player_index(
+                                        &state.players,
+                                        skipped_index,
+                                        classic.direction,
+                                    )
+                                }
+                                Some(TurnEffect::PassBombBack) => {
+                                    state.players.iter_mut().for_each(|player| {
+                                        player.events.push(GameStateEvent::BombPassedBack)
+                                    });
+                                    next_active_player_index(
+                                        &state.players,
+                                        classic.current_turn_index,
+                                        reversed(classic.direction),
+                                    )
+                                }
+                            };
+                            let next_player_identity =
+                                state.players[next_player_index as usize].player_identity;
+                            if let Some(player) = state
                                 .players
                                 .iter_mut()
                                 .find(|player| player.player_identity == next_player_identity)
-                                .map(|player| player.events.push(GameStateEvent::MyTurn));
+                            {
+                                player.events.push(GameStateEvent::MyTurn)
+                            }
                             classic.current_turn_index = next_player_index;
                             state.turn_number += 1;
                         }
@@ -415,43 +1375,22 @@ fn end_turn(
                             state.turn_number += 1;
                         }
                     }
-                    return ShouldScheduleTurnTimeout::ScheduleTurnTimeout;
+                    ShouldScheduleTurnTimeout::ScheduleTurnTimeout
                 }
                 GameResult::Winner(winner) => {
                     // Store example for the final trigram before game ends
                     let final_trigram = state.current_trigram.clone();
-                    store_trigram_example(state, &final_trigram, rng);
-                    state.winner = GameResult::Winner(winner);
-                    match game_state
-                        .player_wins
-                        .iter_mut()
-                        .find(|w| w.player_identity == winner)
-                    {
-                        Some(wins) => {
-                            wins.wins += 1;
-                        }
-                        None => {
-                            game_state.player_wins.push(PlayerWins {
-                                player_identity: winner,
-                                wins: 1,
-                            });
-                        }
-                    }
-                    state.players.iter_mut().for_each(|player| {
-                        player.events.push(if player.player_identity == winner {
-                            GameStateEvent::IWin
-                        } else {
-                            GameStateEvent::ILose
-                        })
-                    });
-                    return ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout;
+                    store_trigram_example(state, &final_trigram, is_blocked, rng);
+                    finish_game(
+                        state,
+                        &mut game_state.player_wins,
+                        GameResult::Winner(winner),
+                    );
+                    ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout
                 }
                 GameResult::Draw => {
-                    state.winner = GameResult::Draw;
-                    state.players.iter_mut().for_each(|player| {
-                        player.events.push(GameStateEvent::ILose);
-                    });
-                    return ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout;
+                    finish_game(state, &mut game_state.player_wins, GameResult::Draw);
+                    ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout
                 }
             }
         }
@@ -459,37 +1398,42 @@ fn end_turn(
 }
 
 // Helper function to check if a word is valid
-fn is_word_valid(word: &str, trigram: &str, used_words: &[String]) -> Result<(), String> {
-    trigram::is_word_valid(word, trigram, used_words)
+fn is_word_valid(
+    word: &str,
+    trigram: &str,
+    used_words: &[String],
+    is_blocked: impl Fn(&str) -> bool,
+) -> Result<(), String> {
+    trigram::is_word_valid(word, trigram, used_words, is_blocked)
 }
 
 // Helper function to update the game state
 fn update_game_state(ctx: &ReducerContext, state: GameStateTable) {
     let mut updated_state = state;
     updated_state.updated_at = ctx.timestamp;
+    lobby::sync_lobby_summary(ctx, &updated_state);
     ctx.db.game_state().game_id().update(updated_state);
 }
 
 fn make_move(
     game_state: &mut GameStateTable,
     game_move: Move,
+    is_blocked: &dyn Fn(&str) -> bool,
     rng: &mut impl rand::RngCore,
 ) -> Result<ShouldScheduleTurnTimeout, String> {
     match &mut game_state.state {
-        GameState::Settings(_) => {
-            return Err("Cannot make moves while in settings state".to_string());
-        }
-        GameState::Countdown(_) => {
-            return Err("Cannot make moves during countdown".to_string());
-        }
+        GameState::Settings(_) => Err("Cannot make moves while in settings state".to_string()),
+        GameState::ReadyCheck(_) => Err("Cannot make moves during ready check".to_string()),
+        GameState::Countdown(_) => Err("Cannot make moves during countdown".to_string()),
         GameState::Playing(state) => {
             if state.players.is_empty() {
                 return Err("No players in game".to_string());
             }
-            if let GameResult::None = has_winner(state) {
-                // game not over
-            } else {
-                return Err("Game is over".to_string());
+            match state.winner {
+                GameResult::None => {}
+                GameResult::Winner(_) | GameResult::Draw => {
+                    return Err("Game is over".to_string());
+                }
             }
             let used_words = get_used_words(state);
             state.players.iter_mut().for_each(|player| {
@@ -505,9 +1449,7 @@ fn make_move(
                             .iter_mut()
                             .find(|p| p.player_identity == current_player_identity)
                         {
-                            None => {
-                                return Err("Player not found".to_string());
-                            }
+                            None => Err("Player not found".to_string()),
                             Some(player) => {
                                 player.current_word = String::new();
                                 player.events.push(GameStateEvent::TimeUp);
@@ -519,26 +1461,16 @@ fn make_move(
                                         {
                                             classic.failed_players.push(current_player_identity);
                                         }
-                                        // Decrement lives if LastPlayerStanding
-                                        if let PlayerWinConditionData::LastPlayerStanding {
-                                            lives,
-                                        } = player.win_condition_data
-                                        {
-                                            let new_lives = (lives - 1).max(0);
-                                            player.win_condition_data =
-                                                PlayerWinConditionData::LastPlayerStanding {
-                                                    lives: new_lives,
-                                                };
+                                        match active_chess_clock(&state.settings) {
+                                            None => lose_life(player, state.turn_number),
+                                            Some(clock) => {
+                                                run_out_of_time(player, clock, state.turn_number)
+                                            }
                                         }
                                         let active_players: Vec<_> = state
                                             .players
                                             .iter()
-                                            .filter(|p| match p.win_condition_data {
-                                                PlayerWinConditionData::LastPlayerStanding {
-                                                    lives,
-                                                } => lives > 0,
-                                                PlayerWinConditionData::UseAllLetters => true,
-                                            })
+                                            .filter(|p| is_active(p))
                                             .map(|p| p.player_identity)
                                             .collect();
                                         let all_active_failed = active_players
@@ -549,7 +1481,7 @@ fn make_move(
                                         }
                                         all_active_failed
                                     }
-                                    WinCondition::UseAllLetters => {
+                                    WinCondition::UseAllLetters | WinCondition::Points { .. } => {
                                         if !classic
                                             .failed_players
                                             .contains(&current_player_identity)
@@ -566,9 +1498,9 @@ fn make_move(
                                     }
                                 };
                                 if pick_new_trigram {
-                                    pick_random_trigram_and_update(state, rng);
+                                    pick_random_trigram_and_update(state, is_blocked, rng);
                                 }
-                                return Ok(end_turn(game_state, rng));
+                                Ok(end_turn(game_state, is_blocked, rng))
                             }
                         }
                     }
@@ -581,35 +1513,124 @@ fn make_move(
                                 .iter()
                                 .any(|g| g.round_number == state.turn_number);
                             if !submitted {
-                                // Decrement lives if LastPlayerStanding
-                                if let PlayerWinConditionData::LastPlayerStanding { lives } =
-                                    player.win_condition_data
-                                {
-                                    let new_lives = (lives - 1).max(0);
-                                    player.win_condition_data =
-                                        PlayerWinConditionData::LastPlayerStanding {
-                                            lives: new_lives,
-                                        };
-                                }
+                                lose_life(player, state.turn_number);
                                 player.events.push(GameStateEvent::TimeUp);
                             }
                         }
-                        pick_random_trigram_and_update(state, rng);
-                        return Ok(end_turn(game_state, rng));
+                        pick_random_trigram_and_update(state, is_blocked, rng);
+                        Ok(end_turn(game_state, is_blocked, rng))
                     }
                 },
+                Move::UsePowerUp(use_power_up) => {
+                    let power_up = use_power_up.power_up;
+                    let player_index = state
+                        .players
+                        .iter()
+                        .position(|p| p.player_identity == use_power_up.player_identity)
+                        .ok_or("Player not found")?;
+                    if !is_active(&state.players[player_index]) {
+                        return Err("You are out of the game".to_string());
+                    }
+                    let inventory_index = state.players[player_index]
+                        .power_ups
+                        .iter()
+                        .position(|p| *p == power_up)
+                        .ok_or("You don't have that power-up")?;
+                    if !power_up_applies(power_up, &state.settings) {
+                        return Err("That power-up can't be used with these settings".to_string());
+                    }
+                    // Everything but the shield acts on the turn itself, so in Classic only the
+                    // player whose turn it is may use it
+                    match (&state.turn_logic, power_up) {
+                        (_, PowerUp::ShieldLife) => {}
+                        (TurnLogic::Classic(classic), _) => {
+                            if classic.current_turn_index as usize != player_index {
+                                return Err(
+                                    "You can only use that power-up on your turn".to_string()
+                                );
+                            }
+                        }
+                        (TurnLogic::Simultaneous(_), _) => {}
+                    }
+
+                    match power_up {
+                        PowerUp::ExtraTime => {
+                            state.extra_time_seconds += EXTRA_TIME_SECONDS;
+                        }
+                        PowerUp::SkipTrigram => {
+                            match &mut state.turn_logic {
+                                TurnLogic::Classic(classic) => classic.failed_players.clear(),
+                                TurnLogic::Simultaneous(_) => {}
+                            }
+                            pick_random_trigram_and_update(state, is_blocked, rng);
+                        }
+                        PowerUp::ReverseTurnOrder => match &mut state.turn_logic {
+                            TurnLogic::Classic(classic) => {
+                                classic.direction = reversed(classic.direction);
+                            }
+                            TurnLogic::Simultaneous(_) => {}
+                        },
+                        PowerUp::SkipNextPlayer => match &mut state.turn_logic {
+                            TurnLogic::Classic(classic) => {
+                                classic.pending_effect = Some(TurnEffect::SkipNextPlayer);
+                            }
+                            TurnLogic::Simultaneous(_) => {}
+                        },
+                        PowerUp::PassBombBack => match &mut state.turn_logic {
+                            TurnLogic::Classic(classic) => {
+                                classic.pending_effect = Some(TurnEffect::PassBombBack);
+                            }
+                            TurnLogic::Simultaneous(_) => {}
+                        },
+                        PowerUp::HardTrigramForNext => {
+                            state.next_trigram_hard = true;
+                        }
+                        PowerUp::ShieldLife => {
+                            if state.players[player_index].shield_active {
+                                return Err("Shield is already active".to_string());
+                            }
+                            state.players[player_index].shield_active = true;
+                        }
+                    }
+
+                    state.players[player_index]
+                        .power_ups
+                        .remove(inventory_index);
+                    state.players.iter_mut().for_each(|player| {
+                        player
+                            .events
+                            .push(GameStateEvent::PowerUpUsed(PowerUpUsedEvent {
+                                player_identity: use_power_up.player_identity,
+                                power_up,
+                            }));
+                    });
+                    Ok(ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout)
+                }
+                Move::MatchTimeUp => {
+                    for player in &mut state.players {
+                        player.current_word = String::new();
+                    }
+                    let final_trigram = state.current_trigram.clone();
+                    store_trigram_example(state, &final_trigram, is_blocked, rng);
+                    let result = limit_result(state);
+                    finish_game(state, &mut game_state.player_wins, result);
+                    Ok(ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout)
+                }
                 Move::GuessWord(guess) => {
                     match state
                         .players
                         .iter_mut()
                         .find(|p| p.player_identity == guess.player_identity)
                     {
-                        None => {
-                            return Err("Player not found".to_string());
-                        }
+                        None => Err("Player not found".to_string()),
                         Some(player) => {
                             let word = guess.word.trim().to_uppercase();
-                            match is_word_valid(&word, &state.current_trigram, &used_words) {
+                            match is_word_valid(
+                                &word,
+                                &state.current_trigram,
+                                &used_words,
+                                |word| state.settings.content_filter && is_blocked(word),
+                            ) {
                                 Ok(()) => {
                                     player
                                         .events
@@ -617,7 +1638,10 @@ fn make_move(
                                     player.past_guesses.push(PastGuess {
                                         word: word.clone(),
                                         round_number: state.turn_number,
+                                        turn_started_at: state.turn_started_at,
+                                        guessed_at: guess.guessed_at,
                                     });
+                                    update_answer_times(player);
                                     for c in word.chars() {
                                         let letter = c.to_string().to_uppercase();
                                         if !player.used_letters.contains(&letter) {
@@ -634,6 +1658,7 @@ fn make_move(
                                             award_random_free_letter(player, rng);
                                         }
                                     }
+                                    award_milestone_power_up(player, &word, &state.settings, rng);
                                     match state.settings.win_condition {
                                         WinCondition::LastPlayerStanding { .. } => {
                                             let has_all_letters = ('A'..='Z').all(|c| {
@@ -651,39 +1676,44 @@ fn make_move(
                                             }
                                         }
                                         WinCondition::UseAllLetters => {}
+                                        WinCondition::Points { .. } => {
+                                            match player.win_condition_data {
+                                                PlayerWinConditionData::Points { score } => {
+                                                    player.win_condition_data =
+                                                        PlayerWinConditionData::Points {
+                                                            score: score
+                                                                + trigram::score_word(
+                                                                    &word,
+                                                                    &state.current_trigram,
+                                                                ),
+                                                        };
+                                                }
+                                                PlayerWinConditionData::LastPlayerStanding {
+                                                    ..
+                                                } => {}
+                                                PlayerWinConditionData::UseAllLetters => {}
+                                            }
+                                        }
                                     }
                                     player.current_word = String::new();
                                     // For Simultaneous, do not pick new trigram or clear failed_players here
                                     // Check for winner after each submission
-                                    if let GameResult::Winner(winner) = has_winner(state) {
+                                    if let GameResult::Winner(winner) =
+                                        has_winner(state, TurnPhase::TurnInProgress)
+                                    {
                                         // End the game immediately if someone wins
                                         let final_trigram = state.current_trigram.clone();
-                                        store_trigram_example(state, &final_trigram, rng);
-                                        state.winner = GameResult::Winner(winner);
-                                        match game_state
-                                            .player_wins
-                                            .iter_mut()
-                                            .find(|w| w.player_identity == winner)
-                                        {
-                                            Some(wins) => {
-                                                wins.wins += 1;
-                                            }
-                                            None => {
-                                                game_state.player_wins.push(PlayerWins {
-                                                    player_identity: winner,
-                                                    wins: 1,
-                                                });
-                                            }
-                                        }
-                                        state.players.iter_mut().for_each(|player| {
-                                            player.events.push(
-                                                if player.player_identity == winner {
-                                                    GameStateEvent::IWin
-                                                } else {
-                                                    GameStateEvent::ILose
-                                                },
-                                            )
-                                        });
+                                        store_trigram_example(
+                                            state,
+                                            &final_trigram,
+                                            is_blocked,
+                                            rng,
+                                        );
+                                        finish_game(
+                                            state,
+                                            &mut game_state.player_wins,
+                                            GameResult::Winner(winner),
+                                        );
                                         return Ok(
                                             ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout,
                                         );
@@ -691,22 +1721,38 @@ fn make_move(
                                     match &mut state.turn_logic {
                                         TurnLogic::Classic(classic) => {
                                             classic.failed_players.clear();
-                                            pick_random_trigram_and_update(state, rng);
-                                            return Ok(end_turn(game_state, rng));
+                                            if let Some(clock) = active_chess_clock(&state.settings)
+                                            {
+                                                let thinking_time = guess
+                                                    .guessed_at
+                                                    .time_duration_since(state.turn_started_at)
+                                                    .unwrap_or(TimeDuration::from_micros(0))
+                                                    - seconds(state.extra_time_seconds);
+                                                let player = &mut state.players
+                                                    [classic.current_turn_index as usize];
+                                                spend_clock_time(player, thinking_time, clock);
+                                            }
+                                            if state.settings.word_turn_effects {
+                                                apply_word_turn_effects(
+                                                    classic,
+                                                    &word,
+                                                    &mut state.players,
+                                                );
+                                            }
+                                            pick_random_trigram_and_update(state, is_blocked, rng);
+                                            Ok(end_turn(game_state, is_blocked, rng))
                                         }
                                         TurnLogic::Simultaneous(_) => {
-                                            return Ok(
-                                                ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout,
-                                            )
+                                            Ok(ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout)
                                         }
-                                    };
+                                    }
                                 }
                                 Err(reason) => {
                                     player.events.push(GameStateEvent::InvalidGuess(
                                         InvalidGuessEvent { word, reason },
                                     ));
                                     player.current_word = String::new();
-                                    return Ok(ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout);
+                                    Ok(ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout)
                                 }
                             }
                         }
@@ -714,16 +1760,21 @@ fn make_move(
                 }
             }
         }
-    };
+    }
 }
 
 // Helper function to get the game state
 fn get_game_state(ctx: &ReducerContext, game_id: u32) -> Option<GameStateTable> {
-    ctx.db.game_state().game_id().find(&game_id)
+    ctx.db.game_state().game_id().find(game_id)
 }
 
 // Helper function to store a trigram example
-fn store_trigram_example(state: &mut PlayingState, trigram: &str, rng: &mut impl rand::RngCore) {
+fn store_trigram_example(
+    state: &mut PlayingState,
+    trigram: &str,
+    is_blocked: &dyn Fn(&str) -> bool,
+    rng: &mut impl rand::RngCore,
+) {
     if !trigram.is_empty() {
         // Collect all PastGuess for the current round from all players
         let mut valid_words = Vec::new();
@@ -741,7 +1792,11 @@ fn store_trigram_example(state: &mut PlayingState, trigram: &str, rng: &mut impl
         }
         let example = TrigramExample {
             trigram: trigram.to_string(),
-            example_words: trigram::get_example_words(trigram, rng),
+            example_words: trigram::get_example_words(
+                trigram,
+                |word| state.settings.content_filter && is_blocked(word),
+                rng,
+            ),
             valid_words,
         };
         state.trigram_examples.insert(0, example);
@@ -749,15 +1804,36 @@ fn store_trigram_example(state: &mut PlayingState, trigram: &str, rng: &mut impl
 }
 
 // Helper function to pick a random trigram and update used trigrams
-fn pick_random_trigram_and_update(state: &mut PlayingState, rng: &mut impl rand::RngCore) {
+fn pick_random_trigram_and_update(
+    state: &mut PlayingState,
+    is_blocked: &dyn Fn(&str) -> bool,
+    rng: &mut impl rand::RngCore,
+) {
     // Store current trigram in a temporary variable
     let current_trigram = state.current_trigram.clone();
     // Store example for current trigram before changing it
-    store_trigram_example(state, &current_trigram, rng);
+    store_trigram_example(state, &current_trigram, is_blocked, rng);
+
+    // No need to push to used_trigrams; just update current_trigram
+    state.current_trigram = pick_random_trigram(state, rng);
+    state.next_trigram_hard = false;
+}
 
+// Helper function to pick an unused trigram, from the hard pool once overtime has started
+// or when a power-up asked for one
+fn pick_random_trigram(state: &PlayingState, rng: &mut impl rand::RngCore) -> String {
     // Compute used trigrams from trigram_examples and current_trigram
     let used_trigrams = get_used_trigrams(state);
-    let available_trigrams = trigram::get_available_trigrams(&used_trigrams);
+    let hard_trigrams = if state.overtime_rounds > 0 || state.next_trigram_hard {
+        trigram::get_hard_trigrams(&used_trigrams)
+    } else {
+        Vec::new()
+    };
+    let available_trigrams = if hard_trigrams.is_empty() {
+        trigram::get_available_trigrams(&used_trigrams)
+    } else {
+        hard_trigrams
+    };
 
     if available_trigrams.is_empty() {
         panic!("Critical error: Ran out of trigrams. This should never happen.");
@@ -765,10 +1841,7 @@ fn pick_random_trigram_and_update(state: &mut PlayingState, rng: &mut impl rand:
 
     // Pick a random trigram from available ones
     let random_index = rng.next_u32() as usize % available_trigrams.len();
-    let new_trigram = available_trigrams[random_index].clone().to_uppercase();
-
-    // No need to push to used_trigrams; just update current_trigram
-    state.current_trigram = new_trigram;
+    available_trigrams[random_index].clone().to_uppercase()
 }
 
 // Helper to compute used trigrams from trigram_examples and current_trigram
@@ -797,30 +1870,73 @@ fn get_used_words(state: &PlayingState) -> Vec<String> {
     words
 }
 
-#[spacetimedb::reducer]
-pub fn create_game(ctx: &ReducerContext, name: String) -> Result<(), String> {
-    // Create new game
+// Helper function to build the settings a freshly created game starts with
+fn default_settings() -> SettingsState {
+    SettingsState {
+        turn_timeout_seconds: 7,
+        players: Vec::new(),
+        win_condition: WinCondition::LastPlayerStanding { starting_lives: 3 },
+        turn_logic_mode: TurnLogicMode::Classic,
+        bonus_letter_word_count: None,
+        max_rounds: None,
+        match_time_limit_seconds: None,
+        limit_tiebreak: TiebreakRule::Lives,
+        overtime_enabled: false,
+        power_ups_enabled: false,
+        word_turn_effects: false,
+        chess_clock: None,
+        countdown_seconds: 5,
+        ready_check_seconds: None,
+        series_best_of: None,
+        visibility: LobbyVisibility::Public,
+        max_players: 8,
+        chat_blocks_trigram: true,
+        content_filter: false,
+    }
+}
+
+// Helper function to insert a new game and its settings state
+fn insert_game(ctx: &ReducerContext, name: String, settings: SettingsState, ranked: bool) -> Game {
     let game = Game {
         id: 0, // Auto-incremented
         name,
         created_at: ctx.timestamp,
         updated_at: ctx.timestamp,
         player_identities: Vec::new(),
+        ranked,
     };
     let game = ctx.db.game().insert(game);
     let game_state = GameStateTable {
         game_id: game.id,
-        state: GameState::Settings(SettingsState {
-            turn_timeout_seconds: 7,
-            players: Vec::new(),
-            win_condition: WinCondition::LastPlayerStanding { starting_lives: 3 },
-            turn_logic_mode: TurnLogicMode::Classic,
-            bonus_letter_word_count: None,
-        }),
+        state: GameState::Settings(settings),
         updated_at: ctx.timestamp,
         player_wins: Vec::new(),
+        series: None,
+        spectators: Vec::new(),
     };
+    lobby::sync_lobby_summary(ctx, &game_state);
     ctx.db.game_state().insert(game_state);
+    game
+}
+
+#[spacetimedb::reducer]
+pub fn create_game(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let game = insert_game(ctx, name, default_settings(), false);
+
+    // Add the creator to the game
+    add_player_to_game(ctx, game.id)?;
+
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn create_game_from_preset(
+    ctx: &ReducerContext,
+    name: String,
+    preset_id: u32,
+) -> Result<(), String> {
+    let settings = presets::preset_settings(ctx, preset_id)?;
+    let game = insert_game(ctx, name, settings, false);
 
     // Add the creator to the game
     add_player_to_game(ctx, game.id)?;
@@ -828,21 +1944,57 @@ pub fn create_game(ctx: &ReducerContext, name: String) -> Result<(), String> {
     Ok(())
 }
 
+// Helper function to delete a game, its state and everything scheduled for it
+fn delete_game_rows(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
+    // A tournament match can't wait on a game that no longer exists
+    tournament::forfeit_match_game(ctx, game_id)?;
+    delete_start_schedules(ctx, game_id);
+    let turn_timeouts: Vec<u64> = ctx
+        .db
+        .turn_timeout_schedule()
+        .iter()
+        .filter(|timeout| timeout.game_id == game_id)
+        .map(|timeout| timeout.scheduled_id)
+        .collect();
+    for scheduled_id in turn_timeouts {
+        ctx.db
+            .turn_timeout_schedule()
+            .scheduled_id()
+            .delete(scheduled_id);
+    }
+    let match_clocks: Vec<u64> = ctx
+        .db
+        .match_clock_schedule()
+        .iter()
+        .filter(|clock| clock.game_id == game_id)
+        .map(|clock| clock.scheduled_id)
+        .collect();
+    for scheduled_id in match_clocks {
+        ctx.db
+            .match_clock_schedule()
+            .scheduled_id()
+            .delete(scheduled_id);
+    }
+    delete_series_next_game_schedules(ctx, game_id);
+    chat::delete_game_messages(ctx, game_id);
+    lobby::remove_lobby_summary(ctx, game_id);
+    // Delete game state first (due to foreign key)
+    ctx.db.game_state().game_id().delete(game_id);
+    // Then delete game
+    ctx.db.game().id().delete(game_id);
+    Ok(())
+}
+
 #[spacetimedb::reducer]
 pub fn delete_game(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
     // Check if game exists
-    let _game = ctx.db.game().id().find(&game_id).ok_or("Game not found")?;
+    let _game = ctx.db.game().id().find(game_id).ok_or("Game not found")?;
 
     // Only allow deletion if game is in Settings state
-    if let Some(game_state) = ctx.db.game_state().game_id().find(&game_id) {
+    if let Some(game_state) = ctx.db.game_state().game_id().find(game_id) {
         match game_state.state {
-            GameState::Settings(_) => {
-                // Delete game state first (due to foreign key)
-                ctx.db.game_state().game_id().delete(&game_id);
-                // Then delete game
-                ctx.db.game().id().delete(&game_id);
-                Ok(())
-            }
+            GameState::Settings(_) => delete_game_rows(ctx, game_id),
+            GameState::ReadyCheck(_) => Err("Cannot delete game during ready check".to_string()),
             GameState::Countdown(_) => Err("Cannot delete game during countdown".to_string()),
             GameState::Playing(_) => Err("Cannot delete game while in progress".to_string()),
         }
@@ -853,47 +2005,89 @@ pub fn delete_game(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
 
 // Initialize the game when the module is first published
 #[spacetimedb::reducer(init)]
-pub fn init(_ctx: &ReducerContext) {
+pub fn init(ctx: &ReducerContext) {
     // No longer create a default game - games will be created by players
+    matchmaking::schedule_ranked_matchmaking(ctx);
+    matchmaking::schedule_quick_play_matchmaking(ctx);
+    cleanup::schedule_game_cleanup(ctx);
+    chat::schedule_chat_cleanup(ctx);
+    username::seed_username_policy(ctx);
+    content_filter::seed_content_filter(ctx);
 }
 
-#[spacetimedb::reducer]
-pub fn update_turn_timeout(ctx: &ReducerContext, game_id: u32, seconds: u32) -> Result<(), String> {
-    if seconds == 0 {
-        return Err("Turn timeout must be greater than 0 seconds".to_string());
-    }
-
-    if let Some(mut game_state) = get_game_state(ctx, game_id) {
-        match &mut game_state.state {
+// Helper function for the settings reducers. Applies a change to a game still in Settings,
+// validates the result, rebuilds the players for it and seats any spectators the change
+// made room for.
+fn update_settings(
+    ctx: &ReducerContext,
+    game_id: u32,
+    setting: &str,
+    change: impl FnOnce(&mut SettingsState) -> Result<(), String>,
+) -> Result<(), String> {
+    match get_game_state(ctx, game_id) {
+        Some(mut game_state) => match &mut game_state.state {
             GameState::Settings(settings) => {
-                settings.turn_timeout_seconds = seconds;
+                change(settings)?;
+                // Check the bounds before starting lives and handicaps are added up
+                validation::validate_settings(settings).map_err(|e| e.to_string())?;
+                // Players carry per-condition data (lives, score) that must match the settings
+                let win_condition = settings.win_condition;
+                settings.players = settings
+                    .players
+                    .iter()
+                    .map(|p| {
+                        create_initial_player_game_data(
+                            p.player_identity,
+                            &win_condition,
+                            p.handicap,
+                        )
+                    })
+                    .collect();
+                fill_open_seats(&mut game_state);
                 update_game_state(ctx, game_state);
                 Ok(())
             }
-            GameState::Countdown(_) => {
-                Err("Cannot update turn timeout during countdown".to_string())
-            }
-            GameState::Playing(_) => {
-                Err("Cannot update turn timeout while game is in progress".to_string())
+            GameState::ReadyCheck(_) => {
+                Err(format!("Cannot update {} during ready check", setting))
             }
-        }
-    } else {
-        Err("Game not initialized".to_string())
+            GameState::Countdown(_) => Err(format!("Cannot update {} during countdown", setting)),
+            GameState::Playing(_) => Err(format!(
+                "Cannot update {} while game is in progress",
+                setting
+            )),
+        },
+        None => Err("Game not initialized".to_string()),
     }
 }
 
+#[spacetimedb::reducer]
+pub fn update_turn_timeout(ctx: &ReducerContext, game_id: u32, seconds: u32) -> Result<(), String> {
+    update_settings(ctx, game_id, "turn timeout", |settings| {
+        settings.turn_timeout_seconds = seconds;
+        Ok(())
+    })
+}
+
+const NO_HANDICAP: PlayerHandicap = PlayerHandicap {
+    extra_seconds: 0,
+    extra_lives: 0,
+    starting_free_letters: 0,
+};
+
 // Helper function to create a new PlayerGameData instance
 fn create_initial_player_game_data(
     player_identity: Identity,
     win_condition: &WinCondition,
+    handicap: PlayerHandicap,
 ) -> PlayerGameData {
     let win_condition_data = match win_condition {
         WinCondition::LastPlayerStanding { starting_lives } => {
             PlayerWinConditionData::LastPlayerStanding {
-                lives: *starting_lives as i32,
+                lives: (*starting_lives + handicap.extra_lives) as i32,
             }
         }
         WinCondition::UseAllLetters => PlayerWinConditionData::UseAllLetters,
+        WinCondition::Points { .. } => PlayerWinConditionData::Points { score: 0 },
     };
     PlayerGameData {
         player_identity,
@@ -903,25 +2097,57 @@ fn create_initial_player_game_data(
         free_letters: Vec::new(),
         past_guesses: Vec::new(),
         events: Vec::new(),
+        eliminated_turn: None,
+        fastest_answer: None,
+        average_answer: None,
+        power_ups: Vec::new(),
+        shield_active: false,
+        handicap,
+        time_bank: TimeDuration::from_micros(0),
+        turns_taken: 0,
     }
 }
 
 #[spacetimedb::reducer]
 pub fn register_player(ctx: &ReducerContext, username: String) -> Result<(), String> {
+    let username = username::validate_username(&username)?;
+    let policy = username::username_policy_or_default(ctx);
     // Check if player info already exists
-    if let Some(mut existing_player_info) = ctx.db.player_info().identity().find(&ctx.sender) {
+    if let Some(mut existing_player_info) = ctx.db.player_info().identity().find(ctx.sender) {
+        // Registering again under the same name just marks the player online
+        if existing_player_info.username != username {
+            if let Some(renamed_at) = existing_player_info.renamed_at {
+                let cooldown_micros = policy.rename_cooldown_seconds as i64 * 1_000_000;
+                if ctx.timestamp.to_micros_since_unix_epoch()
+                    - renamed_at.to_micros_since_unix_epoch()
+                    < cooldown_micros
+                {
+                    return Err("You changed your username too recently".to_string());
+                }
+            }
+            if policy.require_unique {
+                username::check_username_available(ctx, ctx.sender, &username)?;
+            }
+            existing_player_info.username_key = username::username_key(&username);
+            existing_player_info.username = username;
+            existing_player_info.renamed_at = Some(ctx.timestamp);
+        }
         // Update existing player info
         existing_player_info.is_online = true;
         existing_player_info.last_active = ctx.timestamp;
-        existing_player_info.username = username;
         ctx.db.player_info().identity().update(existing_player_info);
     } else {
+        if policy.require_unique {
+            username::check_username_available(ctx, ctx.sender, &username)?;
+        }
         // Create new player info
         let player_info = PlayerInfoTable {
             identity: ctx.sender,
+            username_key: username::username_key(&username),
             username,
             is_online: true,
             last_active: ctx.timestamp,
+            renamed_at: None,
         };
         ctx.db.player_info().insert(player_info);
     }
@@ -931,20 +2157,29 @@ pub fn register_player(ctx: &ReducerContext, username: String) -> Result<(), Str
 
 #[spacetimedb::reducer]
 pub fn add_player_to_game(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
+    add_identity_to_game(ctx, game_id, ctx.sender)
+}
+
+// Helper function to seat a player in a game that is still in settings
+fn add_identity_to_game(
+    ctx: &ReducerContext,
+    game_id: u32,
+    player_identity: Identity,
+) -> Result<(), String> {
     let win_condition = if let Some(game_state) = get_game_state(ctx, game_id) {
         match &game_state.state {
-            GameState::Settings(settings) => settings.win_condition.clone(),
+            GameState::Settings(settings) => settings.win_condition,
             _ => WinCondition::LastPlayerStanding { starting_lives: 3 },
         }
     } else {
         WinCondition::LastPlayerStanding { starting_lives: 3 }
     };
-    let player = create_initial_player_game_data(ctx.sender, &win_condition);
+    let player = create_initial_player_game_data(player_identity, &win_condition, NO_HANDICAP);
 
     // Update game's player_identities list
-    if let Some(mut game) = ctx.db.game().id().find(&game_id) {
-        if !game.player_identities.contains(&ctx.sender) {
-            game.player_identities.push(ctx.sender);
+    if let Some(mut game) = ctx.db.game().id().find(game_id) {
+        if !game.player_identities.contains(&player_identity) {
+            game.player_identities.push(player_identity);
             game.updated_at = ctx.timestamp;
             ctx.db.game().id().update(game);
         }
@@ -957,15 +2192,23 @@ pub fn add_player_to_game(ctx: &ReducerContext, game_id: u32) -> Result<(), Stri
                 if settings
                     .players
                     .iter()
-                    .any(|p| p.player_identity == ctx.sender)
+                    .any(|p| p.player_identity == player_identity)
                 {
                     return Err("Player already registered".to_string());
                 }
+                if game_state.spectators.contains(&player_identity) {
+                    return Err("Already waiting for a seat".to_string());
+                }
 
-                settings.players.push(player);
+                if settings.players.len() >= settings.max_players as usize {
+                    game_state.spectators.push(player_identity);
+                } else {
+                    settings.players.push(player);
+                }
                 update_game_state(ctx, game_state);
                 Ok(())
             }
+            GameState::ReadyCheck(_) => Err("Cannot register during ready check".to_string()),
             GameState::Countdown(_) => Err("Cannot register during countdown".to_string()),
             GameState::Playing(_) => Err("Cannot register while game is in progress".to_string()),
         }
@@ -980,20 +2223,19 @@ pub fn remove_player(
     game_id: u32,
     player_identity: Identity,
 ) -> Result<(), String> {
-    // Update game's player_identities list
-    if let Some(mut game) = ctx.db.game().id().find(&game_id) {
-        if let Some(pos) = game
-            .player_identities
+    remove_identity_from_game_list(ctx, game_id, player_identity);
+
+    if let Some(mut game_state) = get_game_state(ctx, game_id) {
+        // Spectators can leave the queue at any point
+        if let Some(index) = game_state
+            .spectators
             .iter()
             .position(|id| *id == player_identity)
         {
-            game.player_identities.remove(pos);
-            game.updated_at = ctx.timestamp;
-            ctx.db.game().id().update(game);
+            game_state.spectators.remove(index);
+            update_game_state(ctx, game_state);
+            return Ok(());
         }
-    }
-
-    if let Some(mut game_state) = get_game_state(ctx, game_id) {
         match &mut game_state.state {
             GameState::Settings(settings) => {
                 // Remove player from settings state
@@ -1003,12 +2245,14 @@ pub fn remove_player(
                     .position(|p| p.player_identity == player_identity)
                 {
                     settings.players.remove(index);
+                    fill_open_seats(&mut game_state);
                     update_game_state(ctx, game_state);
                     Ok(())
                 } else {
                     Err("Player not found".to_string())
                 }
             }
+            GameState::ReadyCheck(_) => Err("Cannot remove player during ready check".to_string()),
             GameState::Countdown(_) => Err("Cannot remove player during countdown".to_string()),
             GameState::Playing(_) => {
                 Err("Cannot remove player while game is in progress".to_string())
@@ -1019,6 +2263,43 @@ pub fn remove_player(
     }
 }
 
+// Helper function to seat spectators, in the order they joined, while the lobby has room
+fn fill_open_seats(game_state: &mut GameStateTable) {
+    match &mut game_state.state {
+        GameState::Settings(settings) => {
+            while settings.players.len() < settings.max_players as usize
+                && !game_state.spectators.is_empty()
+            {
+                let player_identity = game_state.spectators.remove(0);
+                settings.players.push(create_initial_player_game_data(
+                    player_identity,
+                    &settings.win_condition,
+                    NO_HANDICAP,
+                ));
+            }
+        }
+        // Seats are only handed out in the lobby
+        GameState::ReadyCheck(_) => {}
+        GameState::Countdown(_) => {}
+        GameState::Playing(_) => {}
+    }
+}
+
+// Helper function to take a player off a game's player_identities list
+fn remove_identity_from_game_list(ctx: &ReducerContext, game_id: u32, player_identity: Identity) {
+    if let Some(mut game) = ctx.db.game().id().find(game_id) {
+        if let Some(pos) = game
+            .player_identities
+            .iter()
+            .position(|id| *id == player_identity)
+        {
+            game.player_identities.remove(pos);
+            game.updated_at = ctx.timestamp;
+            ctx.db.game().id().update(game);
+        }
+    }
+}
+
 #[spacetimedb::reducer]
 pub fn start_game(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
     if let Some(mut game_state) = get_game_state(ctx, game_id) {
@@ -1032,21 +2313,26 @@ pub fn start_game(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
                     return Err("Cannot start game with less than 2 players".to_string());
                 }
 
-                // Start a 5 second countdown
-                let countdown_state = CountdownState {
-                    countdown_seconds: 5,
-                    settings: settings.clone(),
-                };
-
-                // Schedule the countdown
-                schedule_game_start(ctx, 5, game_id);
-
-                // Update game state to countdown
-                game_state.state = GameState::Countdown(countdown_state);
+                validation::validate_settings(settings).map_err(|e| e.to_string())?;
+
+                let settings = settings.clone();
+                game_state.series = new_series(&settings);
+                match settings.ready_check_seconds {
+                    Some(seconds) => {
+                        schedule_ready_check_timeout(ctx, seconds, game_id);
+                        game_state.state = GameState::ReadyCheck(ReadyCheckState {
+                            settings,
+                            ready_players: Vec::new(),
+                            started_at: ctx.timestamp,
+                        });
+                    }
+                    None => begin_countdown(ctx, &mut game_state, settings),
+                }
                 update_game_state(ctx, game_state);
 
                 Ok(())
             }
+            GameState::ReadyCheck(_) => Err("Game is already in ready check".to_string()),
             GameState::Countdown(_) => Err("Game is already in countdown".to_string()),
             GameState::Playing(_) => Err("Game already in progress".to_string()),
         }
@@ -1058,7 +2344,7 @@ pub fn start_game(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
 #[spacetimedb::reducer(client_connected)]
 pub fn identity_connected(ctx: &ReducerContext) {
     // Update player info table
-    if let Some(mut player_info) = ctx.db.player_info().identity().find(&ctx.sender) {
+    if let Some(mut player_info) = ctx.db.player_info().identity().find(ctx.sender) {
         player_info.is_online = true;
         player_info.last_active = ctx.timestamp;
         ctx.db.player_info().identity().update(player_info);
@@ -1068,10 +2354,17 @@ pub fn identity_connected(ctx: &ReducerContext) {
 #[spacetimedb::reducer(client_disconnected)]
 pub fn identity_disconnected(ctx: &ReducerContext) {
     // Update player info table
-    if let Some(mut player_info) = ctx.db.player_info().identity().find(&ctx.sender) {
+    if let Some(mut player_info) = ctx.db.player_info().identity().find(ctx.sender) {
         player_info.is_online = false;
+        player_info.last_active = ctx.timestamp;
         ctx.db.player_info().identity().update(player_info);
     }
+
+    // Nobody can be matched while they are away
+    matchmaking::remove_from_ranked_queue(ctx, ctx.sender);
+    matchmaking::remove_from_quick_play_queue(ctx, ctx.sender);
+
+    cancel_starts_without_enough_players(ctx, ctx.sender);
 }
 
 #[spacetimedb::reducer]
@@ -1079,6 +2372,7 @@ pub fn update_current_word(ctx: &ReducerContext, game_id: u32, word: String) ->
     if let Some(mut game_state) = get_game_state(ctx, game_id) {
         match &mut game_state.state {
             GameState::Settings(_) => Err("Game not in playing state".to_string()),
+            GameState::ReadyCheck(_) => Err("Cannot update word during ready check".to_string()),
             GameState::Countdown(_) => Err("Cannot update word during countdown".to_string()),
             GameState::Playing(playing_state) => {
                 // Find the player's index
@@ -1106,27 +2400,211 @@ pub fn update_current_word(ctx: &ReducerContext, game_id: u32, word: String) ->
     }
 }
 
+// Helper function for everything that happens outside the game state when a game ends
+fn handle_game_over(
+    ctx: &ReducerContext,
+    game_id: u32,
+    state: &PlayingState,
+    series: &mut Option<SeriesState>,
+) -> Result<(), String> {
+    // Only ranked queue games are rated. Handicapped games are uneven on purpose, so they
+    // don't count either.
+    let ranked = ctx
+        .db
+        .game()
+        .id()
+        .find(game_id)
+        .is_some_and(|game| game.ranked);
+    if ranked && state.players.iter().all(|p| p.handicap == NO_HANDICAP) {
+        let placements: Vec<(Identity, u32)> = state
+            .placements
+            .iter()
+            .map(|p| (p.player_identity, p.placement))
+            .collect();
+        rating::record_placements(ctx, &placements);
+    }
+
+    if let Some(series) = series {
+        if series.winner.is_none() {
+            record_series_game(series, &state.winner);
+            if series.winner.is_none() {
+                schedule_next_series_game(ctx, game_id, state.started_at);
+            }
+        }
+    }
+
+    // A tournament match played as a series is only decided once the series is
+    let match_result = match series {
+        Some(series) => match series.winner {
+            Some(winner) => GameResult::Winner(winner),
+            None => GameResult::None,
+        },
+        None => state.winner.clone(),
+    };
+    tournament::record_game_result(ctx, game_id, &match_result)
+}
+
+// Helper function to count a finished game towards its series. Draws are replayed.
+fn record_series_game(series: &mut SeriesState, result: &GameResult) {
+    series.games_played += 1;
+    match *result {
+        GameResult::Winner(winner) => {
+            let wins = match series.wins.iter_mut().find(|w| w.player_identity == winner) {
+                Some(wins) => {
+                    wins.wins += 1;
+                    wins.wins
+                }
+                None => {
+                    series.wins.push(PlayerWins {
+                        player_identity: winner,
+                        wins: 1,
+                    });
+                    1
+                }
+            };
+            if wins > series.best_of / 2 {
+                series.winner = Some(winner);
+            }
+        }
+        GameResult::Draw => {}
+        GameResult::None => {}
+    }
+}
+
+const SERIES_INTERMISSION_SECONDS: u32 = 10;
+
+#[spacetimedb::table(name = series_next_game_schedule, scheduled(start_next_series_game))]
+pub struct SeriesNextGameSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    game_id: u32,
+    started_at: Timestamp, // Identifies the finished game this follows on from
+}
+
+// Helper function to schedule the next game of a series after a short intermission
+fn schedule_next_series_game(ctx: &ReducerContext, game_id: u32, started_at: Timestamp) {
+    let timeout_micros = (SERIES_INTERMISSION_SECONDS as i64) * 1_000_000;
+    ctx.db
+        .series_next_game_schedule()
+        .insert(SeriesNextGameSchedule {
+            scheduled_id: 0, // Auto-incremented
+            scheduled_at: (ctx.timestamp + TimeDuration::from_micros(timeout_micros)).into(),
+            game_id,
+            started_at,
+        });
+}
+
+// Helper function to cancel a series game waiting out its intermission
+fn delete_series_next_game_schedules(ctx: &ReducerContext, game_id: u32) {
+    let series_games: Vec<u64> = ctx
+        .db
+        .series_next_game_schedule()
+        .iter()
+        .filter(|next_game| next_game.game_id == game_id)
+        .map(|next_game| next_game.scheduled_id)
+        .collect();
+    for scheduled_id in series_games {
+        ctx.db
+            .series_next_game_schedule()
+            .scheduled_id()
+            .delete(scheduled_id);
+    }
+}
+
+// Helper function to start an empty series when the settings ask for one
+fn new_series(settings: &SettingsState) -> Option<SeriesState> {
+    settings.series_best_of.map(|best_of| SeriesState {
+        best_of,
+        wins: Vec::new(),
+        games_played: 0,
+        winner: None,
+    })
+}
+
+// Helper function to pick the series a rematch belongs to. A decided series, or one whose
+// players changed, starts over. Otherwise the rematch is the series' next game.
+fn series_for_rematch(
+    series: Option<SeriesState>,
+    settings: &SettingsState,
+    roster_changed: bool,
+) -> Option<SeriesState> {
+    match series {
+        Some(series) if series.winner.is_none() && !roster_changed => Some(series),
+        Some(_) | None => new_series(settings),
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn start_next_series_game(
+    ctx: &ReducerContext,
+    arg: SeriesNextGameSchedule,
+) -> Result<(), String> {
+    if let Some(mut game_state) = get_game_state(ctx, arg.game_id) {
+        // Protect against the lobby having moved on (restart, rematch) during the intermission
+        let playing_state = match &game_state.state {
+            GameState::Playing(playing_state) => playing_state,
+            GameState::Settings(_) => return Ok(()),
+            GameState::ReadyCheck(_) => return Ok(()),
+            GameState::Countdown(_) => return Ok(()),
+        };
+        if playing_state.started_at != arg.started_at {
+            return Ok(());
+        }
+        let win_condition = playing_state.settings.win_condition;
+        let players = playing_state
+            .players
+            .iter()
+            .map(|p| create_initial_player_game_data(p.player_identity, &win_condition, p.handicap))
+            .collect();
+        let settings = SettingsState {
+            players,
+            ..playing_state.settings.clone()
+        };
+        begin_countdown(ctx, &mut game_state, settings);
+        update_game_state(ctx, game_state);
+    }
+    Ok(())
+}
+
 fn update_game_state_and_schedule_turn_timeout(
     ctx: &ReducerContext,
     game_id: u32,
     game_move: Move,
 ) -> Result<(), String> {
     match get_game_state(ctx, game_id) {
-        Some(mut game_state) => make_move(&mut game_state, game_move, &mut ctx.rng()).map(
-            |should_schedule_turn_timeout| {
-                match &mut game_state.state {
-                    GameState::Settings(_) => {}
-                    GameState::Countdown(_) => {}
-                    GameState::Playing(playing_state) => match should_schedule_turn_timeout {
+        Some(mut game_state) => {
+            let should_schedule_turn_timeout = make_move(
+                &mut game_state,
+                game_move,
+                &|word| content_filter::is_blocked(ctx, word),
+                &mut ctx.rng(),
+            )?;
+            match &mut game_state.state {
+                GameState::Settings(_) => {}
+                GameState::ReadyCheck(_) => {}
+                GameState::Countdown(_) => {}
+                GameState::Playing(playing_state) => {
+                    match should_schedule_turn_timeout {
                         ShouldScheduleTurnTimeout::ScheduleTurnTimeout => {
-                            schedule_turn_timeout(ctx, playing_state, game_id);
+                            begin_turn(ctx, playing_state, game_id);
                         }
                         ShouldScheduleTurnTimeout::DoNotScheduleTurnTimeout => {}
-                    },
+                    }
+                    // make_move rejects moves once the game is over, so any result here was
+                    // declared by this move
+                    match playing_state.winner {
+                        GameResult::None => {}
+                        GameResult::Winner(_) | GameResult::Draw => {
+                            handle_game_over(ctx, game_id, playing_state, &mut game_state.series)?;
+                        }
+                    }
                 }
-                update_game_state(ctx, game_state);
-            },
-        ),
+            }
+            update_game_state(ctx, game_state);
+            Ok(())
+        }
 
         None => Err("Game not initialized".to_string()),
     }
@@ -1165,10 +2643,203 @@ pub fn submit_word(
         Move::GuessWord(GuessWordMove {
             word,
             player_identity: ctx.sender,
+            guessed_at: ctx.timestamp,
         }),
     )
 }
 
+// A rematch starts once more than half the players, and at least two, have voted for one
+fn rematch_threshold_reached(votes: usize, players: usize) -> bool {
+    votes >= 2 && votes * 2 > players
+}
+
+// Helper function to build the rematch lobby: the same settings with fresh data for each
+// player who voted for it
+fn rematch_settings(state: &PlayingState) -> SettingsState {
+    let win_condition = state.settings.win_condition;
+    let players = state
+        .players
+        .iter()
+        .filter(|p| state.rematch_votes.contains(&p.player_identity))
+        .map(|p| create_initial_player_game_data(p.player_identity, &win_condition, p.handicap))
+        .collect();
+    SettingsState {
+        players,
+        ..state.settings.clone()
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn vote_rematch(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
+    if let Some(mut game_state) = get_game_state(ctx, game_id) {
+        match &mut game_state.state {
+            GameState::Playing(playing_state) => {
+                match playing_state.winner {
+                    GameResult::None => {
+                        return Err("Can only vote for a rematch once the game is over".to_string())
+                    }
+                    GameResult::Winner(_) | GameResult::Draw => {}
+                }
+                if !playing_state
+                    .players
+                    .iter()
+                    .any(|p| p.player_identity == ctx.sender)
+                {
+                    return Err("Player not in this game".to_string());
+                }
+                // Clear all player events
+                for player in &mut playing_state.players {
+                    player.events.clear();
+                }
+                if !playing_state.rematch_votes.contains(&ctx.sender) {
+                    playing_state.rematch_votes.push(ctx.sender);
+                }
+
+                if rematch_threshold_reached(
+                    playing_state.rematch_votes.len(),
+                    playing_state.players.len(),
+                ) {
+                    // Players who didn't vote are left out of the rematch
+                    let non_voters: Vec<Identity> = playing_state
+                        .players
+                        .iter()
+                        .map(|p| p.player_identity)
+                        .filter(|identity| !playing_state.rematch_votes.contains(identity))
+                        .collect();
+                    for player_identity in &non_voters {
+                        remove_identity_from_game_list(ctx, game_id, *player_identity);
+                    }
+                    let roster_changed = !non_voters.is_empty();
+                    let settings = rematch_settings(playing_state);
+                    // The rematch replaces any scheduled next game of the series
+                    game_state.series =
+                        series_for_rematch(game_state.series.take(), &settings, roster_changed);
+                    delete_series_next_game_schedules(ctx, game_id);
+                    begin_countdown(ctx, &mut game_state, settings);
+                }
+                update_game_state(ctx, game_state);
+                Ok(())
+            }
+            GameState::Settings(_) => Err("Can only vote for a rematch after a game".to_string()),
+            GameState::ReadyCheck(_) => {
+                Err("Cannot vote for a rematch during ready check".to_string())
+            }
+            GameState::Countdown(_) => {
+                Err("Cannot vote for a rematch during countdown".to_string())
+            }
+        }
+    } else {
+        Err("Game not initialized".to_string())
+    }
+}
+
+const MAX_STORED_REACTIONS: usize = 20;
+// Each player can react at most once in this many milliseconds
+const REACTION_COOLDOWN_MILLIS: i64 = 1_000;
+
+#[spacetimedb::reducer]
+pub fn react(
+    ctx: &ReducerContext,
+    game_id: u32,
+    emote: Emote,
+    target: ReactionTarget,
+) -> Result<(), String> {
+    if let Some(mut game_state) = get_game_state(ctx, game_id) {
+        let spectating = game_state.spectators.contains(&ctx.sender);
+        match &mut game_state.state {
+            GameState::Playing(playing_state) => {
+                add_reaction(
+                    playing_state,
+                    ctx.sender,
+                    spectating,
+                    emote,
+                    target,
+                    ctx.timestamp,
+                )?;
+                update_game_state(ctx, game_state);
+                Ok(())
+            }
+            GameState::Settings(_) => Err("Can only react during a game".to_string()),
+            GameState::ReadyCheck(_) => Err("Cannot react during ready check".to_string()),
+            GameState::Countdown(_) => Err("Cannot react during countdown".to_string()),
+        }
+    } else {
+        Err("Game not initialized".to_string())
+    }
+}
+
+// Helper function to check a reaction and add it to the game, telling the player it targets
+fn add_reaction(
+    playing_state: &mut PlayingState,
+    from: Identity,
+    spectating: bool,
+    emote: Emote,
+    target: ReactionTarget,
+    now: Timestamp,
+) -> Result<(), String> {
+    if !spectating
+        && !playing_state
+            .players
+            .iter()
+            .any(|p| p.player_identity == from)
+    {
+        return Err("Only players and spectators can react".to_string());
+    }
+    let target_exists = match &target {
+        ReactionTarget::Player(identity) => playing_state
+            .players
+            .iter()
+            .any(|p| p.player_identity == *identity),
+        ReactionTarget::Guess(guess) => playing_state.players.iter().any(|p| {
+            p.player_identity == guess.player_identity
+                && p.past_guesses.iter().any(|g| g.word == guess.word)
+        }),
+    };
+    if !target_exists {
+        return Err("Reaction target not found".to_string());
+    }
+
+    let cooldown_micros = REACTION_COOLDOWN_MILLIS * 1_000;
+    let throttled = playing_state.reactions.iter().rev().any(|reaction| {
+        reaction.from == from
+            && now.to_micros_since_unix_epoch() - reaction.reacted_at.to_micros_since_unix_epoch()
+                < cooldown_micros
+    });
+    if throttled {
+        return Err("Reacting too quickly, slow down".to_string());
+    }
+
+    let target_identity = match &target {
+        ReactionTarget::Player(identity) => *identity,
+        ReactionTarget::Guess(guess) => guess.player_identity,
+    };
+    let reaction = Reaction {
+        from,
+        emote,
+        target,
+        reacted_at: now,
+    };
+    // Clear all player events
+    for player in &mut playing_state.players {
+        player.events.clear();
+    }
+    // Only the player reacted to gets the event, everyone else sees the reactions list
+    if let Some(player) = playing_state
+        .players
+        .iter_mut()
+        .find(|p| p.player_identity == target_identity)
+    {
+        player
+            .events
+            .push(GameStateEvent::Reaction(reaction.clone()));
+    }
+    playing_state.reactions.push(reaction);
+    if playing_state.reactions.len() > MAX_STORED_REACTIONS {
+        playing_state.reactions.remove(0);
+    }
+    Ok(())
+}
+
 #[spacetimedb::reducer]
 pub fn restart_game(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
     if let Some(mut game_state) = get_game_state(ctx, game_id) {
@@ -1179,16 +2850,20 @@ pub fn restart_game(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
                 let reset_players: Vec<PlayerGameData> = playing_state
                     .players
                     .iter()
-                    .map(|p| create_initial_player_game_data(p.player_identity, win_condition))
+                    .map(|p| {
+                        create_initial_player_game_data(
+                            p.player_identity,
+                            win_condition,
+                            p.handicap,
+                        )
+                    })
                     .collect();
 
                 game_state.state = GameState::Settings(SettingsState {
-                    turn_timeout_seconds: playing_state.settings.turn_timeout_seconds,
                     players: reset_players,
-                    win_condition: win_condition.clone(),
-                    turn_logic_mode: playing_state.settings.turn_logic_mode,
-                    bonus_letter_word_count: playing_state.settings.bonus_letter_word_count,
+                    ..playing_state.settings.clone()
                 });
+                fill_open_seats(&mut game_state);
                 game_state.updated_at = ctx.timestamp;
                 update_game_state(ctx, game_state);
                 Ok(())
@@ -1197,6 +2872,7 @@ pub fn restart_game(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
                 // Already in settings state, nothing to do
                 Ok(())
             }
+            GameState::ReadyCheck(_) => Err("Cannot restart game during ready check".to_string()),
             GameState::Countdown(_) => Err("Cannot restart game during countdown".to_string()),
         }
     } else {
@@ -1210,18 +2886,10 @@ pub fn update_win_condition(
     game_id: u32,
     win_condition: WinCondition,
 ) -> Result<(), String> {
-    if let Some(mut game_state) = get_game_state(ctx, game_id) {
-        match &mut game_state.state {
-            GameState::Settings(settings) => {
-                settings.win_condition = win_condition;
-                update_game_state(ctx, game_state);
-                Ok(())
-            }
-            _ => Err("Can only update win condition in Settings state".to_string()),
-        }
-    } else {
-        Err("Game not initialized".to_string())
-    }
+    update_settings(ctx, game_id, "win condition", |settings| {
+        settings.win_condition = win_condition;
+        Ok(())
+    })
 }
 
 #[spacetimedb::reducer]
@@ -1230,18 +2898,10 @@ pub fn update_turn_logic_mode(
     game_id: u32,
     turn_logic_mode: TurnLogicMode,
 ) -> Result<(), String> {
-    if let Some(mut game_state) = get_game_state(ctx, game_id) {
-        match &mut game_state.state {
-            GameState::Settings(settings) => {
-                settings.turn_logic_mode = turn_logic_mode;
-                update_game_state(ctx, game_state);
-                Ok(())
-            }
-            _ => Err("Can only update turn logic mode in Settings state".to_string()),
-        }
-    } else {
-        Err("Game not initialized".to_string())
-    }
+    update_settings(ctx, game_id, "turn logic mode", |settings| {
+        settings.turn_logic_mode = turn_logic_mode;
+        Ok(())
+    })
 }
 
 #[spacetimedb::reducer]
@@ -1250,36 +2910,22 @@ pub fn update_starting_lives(
     game_id: u32,
     starting_lives: u32,
 ) -> Result<(), String> {
-    if starting_lives == 0 {
-        return Err("Starting lives must be greater than 0".to_string());
-    }
-    if let Some(mut game_state) = get_game_state(ctx, game_id) {
-        match &mut game_state.state {
-            GameState::Settings(settings) => {
-                if let WinCondition::LastPlayerStanding {
-                    starting_lives: ref mut lives,
-                } = &mut settings.win_condition
-                {
-                    *lives = starting_lives;
-                    for player in &mut settings.players {
-                        player.win_condition_data = PlayerWinConditionData::LastPlayerStanding {
-                            lives: starting_lives as i32,
-                        };
-                    }
-                }
-                update_game_state(ctx, game_state);
+    update_settings(
+        ctx,
+        game_id,
+        "starting lives",
+        |settings| match &mut settings.win_condition {
+            WinCondition::LastPlayerStanding {
+                starting_lives: lives,
+            } => {
+                *lives = starting_lives;
                 Ok(())
             }
-            GameState::Countdown(_) => {
-                Err("Cannot update starting lives during countdown".to_string())
+            WinCondition::UseAllLetters | WinCondition::Points { .. } => {
+                Err(validation::SettingsError::StartingLivesNeedLastPlayerStanding.to_string())
             }
-            GameState::Playing(_) => {
-                Err("Cannot update starting lives while game is in progress".to_string())
-            }
-        }
-    } else {
-        Err("Game not initialized".to_string())
-    }
+        },
+    )
 }
 
 #[spacetimedb::reducer]
@@ -1288,24 +2934,208 @@ pub fn update_bonus_letter_word_count(
     game_id: u32,
     count: Option<u32>,
 ) -> Result<(), String> {
-    if let Some(mut game_state) = get_game_state(ctx, game_id) {
-        match &mut game_state.state {
-            GameState::Settings(settings) => {
-                // Validate: if Some, must be >= 1
-                if let Some(n) = count {
-                    if n < 1 {
-                        return Err("Bonus letter word count must be at least 1".to_string());
-                    }
-                }
-                settings.bonus_letter_word_count = count;
-                update_game_state(ctx, game_state);
-                Ok(())
-            }
-            _ => Err("Can only update bonus letter word count in Settings state".to_string()),
-        }
-    } else {
-        Err("Game not initialized".to_string())
-    }
+    update_settings(ctx, game_id, "bonus letter word count", |settings| {
+        settings.bonus_letter_word_count = count;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_max_rounds(
+    ctx: &ReducerContext,
+    game_id: u32,
+    max_rounds: Option<u32>,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "maximum rounds", |settings| {
+        settings.max_rounds = max_rounds;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_match_time_limit(
+    ctx: &ReducerContext,
+    game_id: u32,
+    seconds: Option<u32>,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "match time limit", |settings| {
+        settings.match_time_limit_seconds = seconds;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_limit_tiebreak(
+    ctx: &ReducerContext,
+    game_id: u32,
+    tiebreak: TiebreakRule,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "limit tiebreak", |settings| {
+        settings.limit_tiebreak = tiebreak;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_overtime_enabled(
+    ctx: &ReducerContext,
+    game_id: u32,
+    enabled: bool,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "overtime", |settings| {
+        settings.overtime_enabled = enabled;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_power_ups_enabled(
+    ctx: &ReducerContext,
+    game_id: u32,
+    enabled: bool,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "power-ups", |settings| {
+        settings.power_ups_enabled = enabled;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_word_turn_effects(
+    ctx: &ReducerContext,
+    game_id: u32,
+    enabled: bool,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "word turn effects", |settings| {
+        settings.word_turn_effects = enabled;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn set_player_handicap(
+    ctx: &ReducerContext,
+    game_id: u32,
+    player_identity: Identity,
+    handicap: PlayerHandicap,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "handicaps", |settings| {
+        let player = settings
+            .players
+            .iter_mut()
+            .find(|p| p.player_identity == player_identity)
+            .ok_or("Player not found")?;
+        player.handicap = handicap;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_chess_clock(
+    ctx: &ReducerContext,
+    game_id: u32,
+    chess_clock: Option<ChessClockSettings>,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "chess clock", |settings| {
+        settings.chess_clock = chess_clock;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_countdown_seconds(
+    ctx: &ReducerContext,
+    game_id: u32,
+    seconds: u32,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "countdown", |settings| {
+        settings.countdown_seconds = seconds;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_ready_check(
+    ctx: &ReducerContext,
+    game_id: u32,
+    seconds: Option<u32>,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "ready check", |settings| {
+        settings.ready_check_seconds = seconds;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_series_best_of(
+    ctx: &ReducerContext,
+    game_id: u32,
+    best_of: Option<u32>,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "series length", |settings| {
+        settings.series_best_of = best_of;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_visibility(
+    ctx: &ReducerContext,
+    game_id: u32,
+    visibility: LobbyVisibility,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "visibility", |settings| {
+        settings.visibility = visibility;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_max_players(
+    ctx: &ReducerContext,
+    game_id: u32,
+    max_players: u32,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "maximum players", |settings| {
+        settings.max_players = max_players;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_chat_blocks_trigram(
+    ctx: &ReducerContext,
+    game_id: u32,
+    chat_blocks_trigram: bool,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "chat filtering", |settings| {
+        settings.chat_blocks_trigram = chat_blocks_trigram;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn update_content_filter(
+    ctx: &ReducerContext,
+    game_id: u32,
+    content_filter: bool,
+) -> Result<(), String> {
+    update_settings(ctx, game_id, "content filter", |settings| {
+        settings.content_filter = content_filter;
+        Ok(())
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn use_power_up(ctx: &ReducerContext, game_id: u32, power_up: PowerUp) -> Result<(), String> {
+    update_game_state_and_schedule_turn_timeout(
+        ctx,
+        game_id,
+        Move::UsePowerUp(UsePowerUpMove {
+            player_identity: ctx.sender,
+            power_up,
+        }),
+    )
 }
 
 // Helper function to award a random free letter to a player and push the event
@@ -1327,3 +3157,784 @@ fn award_random_free_letter(player: &mut PlayerGameData, rng: &mut impl rand::Rn
             }));
     }
 }
+
+const ALL_POWER_UPS: [PowerUp; 7] = [
+    PowerUp::ExtraTime,
+    PowerUp::SkipTrigram,
+    PowerUp::ReverseTurnOrder,
+    PowerUp::HardTrigramForNext,
+    PowerUp::ShieldLife,
+    PowerUp::SkipNextPlayer,
+    PowerUp::PassBombBack,
+];
+const POWER_UP_WORD_MILESTONE: usize = 5; // Every this many accepted words earns a power-up
+const POWER_UP_LONG_WORD_LENGTH: usize = 14; // So does any word at least this long
+const MAX_POWER_UPS: usize = 3;
+const EXTRA_TIME_SECONDS: u32 = 5;
+
+// Helper function to check whether a power-up does anything under the game's settings
+fn power_up_applies(power_up: PowerUp, settings: &SettingsState) -> bool {
+    match power_up {
+        PowerUp::ExtraTime => true,
+        PowerUp::HardTrigramForNext => true,
+        PowerUp::SkipTrigram
+        | PowerUp::ReverseTurnOrder
+        | PowerUp::SkipNextPlayer
+        | PowerUp::PassBombBack => match settings.turn_logic_mode {
+            TurnLogicMode::Classic => true,
+            TurnLogicMode::Simultaneous => false,
+        },
+        PowerUp::ShieldLife => match settings.win_condition {
+            WinCondition::LastPlayerStanding { .. } => true,
+            WinCondition::UseAllLetters => false,
+            WinCondition::Points { .. } => false,
+        },
+    }
+}
+
+// Helper function to award a random power-up when an accepted word hits a milestone
+fn award_milestone_power_up(
+    player: &mut PlayerGameData,
+    word: &str,
+    settings: &SettingsState,
+    rng: &mut impl rand::RngCore,
+) {
+    let milestone = player
+        .past_guesses
+        .len()
+        .is_multiple_of(POWER_UP_WORD_MILESTONE)
+        || word.len() >= POWER_UP_LONG_WORD_LENGTH;
+    if !settings.power_ups_enabled || !milestone || player.power_ups.len() >= MAX_POWER_UPS {
+        return;
+    }
+    let options: Vec<PowerUp> = ALL_POWER_UPS
+        .iter()
+        .copied()
+        .filter(|power_up| power_up_applies(*power_up, settings))
+        .collect();
+    if !options.is_empty() {
+        let power_up = options[rng.next_u32() as usize % options.len()];
+        player.power_ups.push(power_up);
+        player.events.push(GameStateEvent::PowerUpAwarded(power_up));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity(n: u8) -> Identity {
+        Identity::from_byte_array([n; 32])
+    }
+
+    fn player_with_lives(n: u8, lives: i32) -> PlayerGameData {
+        let mut player = create_initial_player_game_data(
+            identity(n),
+            &WinCondition::LastPlayerStanding { starting_lives: 3 },
+            NO_HANDICAP,
+        );
+        player.win_condition_data = PlayerWinConditionData::LastPlayerStanding { lives };
+        player
+    }
+
+    fn finished_state(players: Vec<PlayerGameData>, winner: GameResult) -> PlayingState {
+        PlayingState {
+            players,
+            turn_logic: TurnLogic::Simultaneous(SimultaneousTurnLogic {}),
+            turn_number: 10,
+            settings: default_settings(),
+            current_trigram: String::new(),
+            started_at: Timestamp::UNIX_EPOCH,
+            turn_started_at: Timestamp::UNIX_EPOCH,
+            trigram_examples: Vec::new(),
+            winner,
+            placements: Vec::new(),
+            overtime_rounds: 0,
+            extra_time_seconds: 0,
+            next_trigram_hard: false,
+            rematch_votes: Vec::new(),
+            reactions: Vec::new(),
+        }
+    }
+
+    fn placements(state: &PlayingState) -> Vec<u32> {
+        compute_placements(state)
+            .iter()
+            .map(|p| p.placement)
+            .collect()
+    }
+
+    #[test]
+    fn next_active_player_skips_eliminated_players() {
+        let players = vec![
+            player_with_lives(1, 3),
+            player_with_lives(2, 0),
+            player_with_lives(3, 1),
+            player_with_lives(4, 2),
+        ];
+        assert_eq!(
+            next_active_player_index(&players, 0, TurnDirection::Forward),
+            2
+        );
+        assert_eq!(
+            next_active_player_index(&players, 3, TurnDirection::Forward),
+            0
+        );
+        assert_eq!(
+            next_active_player_index(&players, 2, TurnDirection::Backward),
+            0
+        );
+        assert_eq!(
+            next_active_player_index(&players, 0, TurnDirection::Backward),
+            3
+        );
+    }
+
+    #[test]
+    fn next_active_player_stays_put_when_alone() {
+        let players = vec![
+            player_with_lives(1, 0),
+            player_with_lives(2, 2),
+            player_with_lives(3, 0),
+        ];
+        assert_eq!(
+            next_active_player_index(&players, 1, TurnDirection::Forward),
+            1
+        );
+        assert_eq!(
+            next_active_player_index(&players, 1, TurnDirection::Backward),
+            1
+        );
+    }
+
+    #[test]
+    fn placements_follow_elimination_order() {
+        let mut second = player_with_lives(2, 0);
+        second.eliminated_turn = Some(7);
+        let mut third = player_with_lives(3, 0);
+        third.eliminated_turn = Some(3);
+        let state = finished_state(
+            vec![third, player_with_lives(1, 2), second],
+            GameResult::Winner(identity(1)),
+        );
+        assert_eq!(placements(&state), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn players_out_on_the_same_turn_share_a_placement() {
+        let mut second = player_with_lives(2, 0);
+        second.eliminated_turn = Some(5);
+        let mut also_second = player_with_lives(3, 0);
+        also_second.eliminated_turn = Some(5);
+        let state = finished_state(
+            vec![player_with_lives(1, 1), second, also_second],
+            GameResult::Winner(identity(1)),
+        );
+        assert_eq!(placements(&state), vec![1, 2, 2]);
+    }
+
+    #[test]
+    fn drawn_players_are_ranked_by_the_tiebreak() {
+        let state = finished_state(
+            vec![player_with_lives(1, 1), player_with_lives(2, 2)],
+            GameResult::Draw,
+        );
+        assert_eq!(placements(&state), vec![2, 1]);
+
+        let state = finished_state(
+            vec![player_with_lives(1, 2), player_with_lives(2, 2)],
+            GameResult::Draw,
+        );
+        assert_eq!(placements(&state), vec![1, 1]);
+    }
+
+    fn guess_answered_in(micros: i64) -> PastGuess {
+        PastGuess {
+            word: "WORD".to_string(),
+            round_number: 1,
+            turn_started_at: Timestamp::from_micros_since_unix_epoch(1_000_000),
+            guessed_at: Timestamp::from_micros_since_unix_epoch(1_000_000 + micros),
+        }
+    }
+
+    #[test]
+    fn answer_times_are_empty_without_guesses() {
+        let mut player = player_with_lives(1, 3);
+        update_answer_times(&mut player);
+        assert!(player.fastest_answer.is_none());
+        assert!(player.average_answer.is_none());
+    }
+
+    #[test]
+    fn answer_times_track_fastest_and_average() {
+        let mut player = player_with_lives(1, 3);
+        player.past_guesses = vec![
+            guess_answered_in(3_000_000),
+            guess_answered_in(1_000_000),
+            guess_answered_in(2_000_000),
+        ];
+        update_answer_times(&mut player);
+        assert_eq!(
+            player.fastest_answer,
+            Some(TimeDuration::from_micros(1_000_000))
+        );
+        assert_eq!(
+            player.average_answer,
+            Some(TimeDuration::from_micros(2_000_000))
+        );
+    }
+
+    fn classic_state(players: Vec<PlayerGameData>) -> PlayingState {
+        PlayingState {
+            turn_logic: TurnLogic::Classic(ClassicTurnLogic {
+                current_turn_index: 0,
+                failed_players: Vec::new(),
+                direction: TurnDirection::Forward,
+                pending_effect: None,
+            }),
+            ..finished_state(players, GameResult::None)
+        }
+    }
+
+    fn player_with_turns(n: u8, lives: i32, turns_taken: u32) -> PlayerGameData {
+        PlayerGameData {
+            turns_taken,
+            ..player_with_lives(n, lives)
+        }
+    }
+
+    #[test]
+    fn classic_round_waits_for_every_active_player() {
+        let mut state = classic_state(vec![
+            player_with_turns(1, 3, 2),
+            player_with_turns(2, 3, 2),
+            player_with_turns(3, 3, 1),
+        ]);
+        assert!(!rounds_reached(&state, TurnPhase::TurnComplete, 2));
+        state.players[2].turns_taken = 2;
+        assert!(rounds_reached(&state, TurnPhase::TurnComplete, 2));
+    }
+
+    #[test]
+    fn classic_round_ignores_eliminated_players() {
+        let state = classic_state(vec![
+            player_with_turns(1, 3, 3),
+            player_with_turns(2, 0, 1),
+            player_with_turns(3, 2, 3),
+        ]);
+        assert!(rounds_reached(&state, TurnPhase::TurnComplete, 3));
+        assert!(!rounds_reached(&state, TurnPhase::TurnComplete, 4));
+    }
+
+    #[test]
+    fn classic_round_survives_a_skipped_turn() {
+        // The third player was skipped, so the others have had an extra turn each
+        let state = classic_state(vec![
+            player_with_turns(1, 3, 3),
+            player_with_turns(2, 3, 3),
+            player_with_turns(3, 3, 2),
+        ]);
+        assert!(rounds_reached(&state, TurnPhase::TurnComplete, 2));
+        assert!(!rounds_reached(&state, TurnPhase::TurnComplete, 3));
+    }
+
+    #[test]
+    fn simultaneous_round_counts_the_current_turn_once_complete() {
+        let state = PlayingState {
+            turn_number: 2,
+            ..finished_state(vec![player_with_lives(1, 3)], GameResult::None)
+        };
+        assert!(!rounds_reached(&state, TurnPhase::TurnInProgress, 3));
+        assert!(rounds_reached(&state, TurnPhase::TurnComplete, 3));
+    }
+
+    #[test]
+    fn finishing_a_classic_turn_counts_it_for_that_player() {
+        let mut game_state = GameStateTable {
+            game_id: 1,
+            state: GameState::Playing(classic_state(vec![
+                player_with_lives(1, 3),
+                player_with_lives(2, 3),
+            ])),
+            updated_at: Timestamp::UNIX_EPOCH,
+            player_wins: Vec::new(),
+            series: None,
+            spectators: Vec::new(),
+        };
+        end_turn(
+            &mut game_state,
+            &|_| false,
+            &mut rand::rngs::mock::StepRng::new(0, 1),
+        );
+        match &game_state.state {
+            GameState::Playing(state) => {
+                assert_eq!(state.players[0].turns_taken, 1);
+                assert_eq!(state.players[1].turns_taken, 0);
+            }
+            _ => panic!("Game should still be playing"),
+        }
+    }
+
+    fn with_round_limit(mut state: PlayingState, max_rounds: u32) -> PlayingState {
+        state.settings.max_rounds = Some(max_rounds);
+        state
+    }
+
+    #[test]
+    fn round_limit_ends_the_game_once_every_round_is_complete() {
+        let state = with_round_limit(
+            classic_state(vec![player_with_turns(1, 3, 4), player_with_turns(2, 2, 4)]),
+            4,
+        );
+        assert!(matches!(
+            has_winner(&state, TurnPhase::TurnComplete),
+            GameResult::Winner(winner) if winner == identity(1)
+        ));
+        // A limit is only checked once a turn has finished
+        assert!(matches!(
+            has_winner(&state, TurnPhase::TurnInProgress),
+            GameResult::None
+        ));
+    }
+
+    #[test]
+    fn round_limit_waits_for_the_round_to_finish() {
+        let state = with_round_limit(
+            classic_state(vec![player_with_turns(1, 3, 4), player_with_turns(2, 2, 3)]),
+            4,
+        );
+        assert!(matches!(
+            has_winner(&state, TurnPhase::TurnComplete),
+            GameResult::None
+        ));
+    }
+
+    #[test]
+    fn limit_result_uses_the_tiebreak_rule() {
+        let mut state = classic_state(vec![player_with_lives(1, 1), player_with_lives(2, 2)]);
+        assert!(matches!(
+            limit_result(&state),
+            GameResult::Winner(winner) if winner == identity(2)
+        ));
+
+        state.settings.limit_tiebreak = TiebreakRule::WordsPlayed;
+        assert!(matches!(limit_result(&state), GameResult::Draw));
+        state.players[0]
+            .past_guesses
+            .push(guess_answered_in(1_000_000));
+        assert!(matches!(
+            limit_result(&state),
+            GameResult::Winner(winner) if winner == identity(1)
+        ));
+    }
+
+    #[test]
+    fn limit_result_ignores_eliminated_players() {
+        let state = classic_state(vec![
+            player_with_lives(1, 0),
+            player_with_lives(2, 1),
+            player_with_lives(3, 1),
+        ]);
+        assert!(matches!(limit_result(&state), GameResult::Draw));
+
+        let mut state = state;
+        state.players[0]
+            .past_guesses
+            .push(guess_answered_in(1_000_000));
+        state.settings.limit_tiebreak = TiebreakRule::WordsPlayed;
+        assert!(matches!(limit_result(&state), GameResult::Draw));
+    }
+
+    fn eliminated_on(n: u8, turn: u32) -> PlayerGameData {
+        PlayerGameData {
+            eliminated_turn: Some(turn),
+            ..player_with_lives(n, 0)
+        }
+    }
+
+    #[test]
+    fn overtime_only_starts_when_enabled_and_everyone_is_out() {
+        let mut state = finished_state(
+            vec![eliminated_on(1, 4), eliminated_on(2, 4)],
+            GameResult::None,
+        );
+        assert!(!should_start_overtime(&state));
+        state.settings.overtime_enabled = true;
+        assert!(should_start_overtime(&state));
+        state.players[1] = player_with_lives(2, 1);
+        assert!(!should_start_overtime(&state));
+    }
+
+    #[test]
+    fn overtime_revives_only_the_last_players_out() {
+        let mut state = finished_state(
+            vec![
+                eliminated_on(1, 3),
+                eliminated_on(2, 7),
+                eliminated_on(3, 7),
+            ],
+            GameResult::None,
+        );
+        revive_last_eliminated(&mut state);
+        assert!(!is_active(&state.players[0]));
+        assert_eq!(state.players[0].eliminated_turn, Some(3));
+        for player in &state.players[1..] {
+            assert!(matches!(
+                player.win_condition_data,
+                PlayerWinConditionData::LastPlayerStanding { lives: 1 }
+            ));
+            assert_eq!(player.eliminated_turn, None);
+        }
+        assert_eq!(state.overtime_rounds, 1);
+        assert!(state
+            .players
+            .iter()
+            .all(|p| matches!(p.events.last(), Some(GameStateEvent::Overtime))));
+    }
+
+    fn playing_table(state: PlayingState) -> GameStateTable {
+        GameStateTable {
+            game_id: 1,
+            state: GameState::Playing(state),
+            updated_at: Timestamp::UNIX_EPOCH,
+            player_wins: Vec::new(),
+            series: None,
+            spectators: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn eliminated_players_cannot_use_power_ups() {
+        let mut out = player_with_lives(2, 0);
+        out.power_ups = vec![PowerUp::ShieldLife, PowerUp::ExtraTime];
+        let mut state = finished_state(vec![player_with_lives(1, 2), out], GameResult::None);
+        state.settings.power_ups_enabled = true;
+        let mut game_state = playing_table(state);
+        for power_up in [PowerUp::ShieldLife, PowerUp::ExtraTime] {
+            let result = make_move(
+                &mut game_state,
+                Move::UsePowerUp(UsePowerUpMove {
+                    player_identity: identity(2),
+                    power_up,
+                }),
+                &|_| false,
+                &mut rand::rngs::mock::StepRng::new(0, 1),
+            );
+            assert_eq!(result.err(), Some("You are out of the game".to_string()));
+        }
+    }
+
+    fn clock(on_empty: ClockExpiry) -> ChessClockSettings {
+        ChessClockSettings {
+            bank_seconds: 60,
+            increment_seconds: 2,
+            on_empty,
+        }
+    }
+
+    #[test]
+    fn spending_clock_time_adds_the_increment() {
+        let mut player = player_with_lives(1, 3);
+        player.time_bank = seconds(30);
+        spend_clock_time(&mut player, seconds(10), clock(ClockExpiry::LoseLife));
+        assert_eq!(player.time_bank, seconds(22));
+    }
+
+    #[test]
+    fn spending_clock_time_never_goes_below_zero() {
+        let mut player = player_with_lives(1, 3);
+        player.time_bank = seconds(5);
+        spend_clock_time(&mut player, seconds(10), clock(ClockExpiry::LoseLife));
+        assert_eq!(player.time_bank, seconds(2));
+    }
+
+    #[test]
+    fn running_out_of_time_can_eliminate() {
+        let mut player = player_with_lives(1, 3);
+        run_out_of_time(&mut player, clock(ClockExpiry::Eliminate), 6);
+        assert!(!is_active(&player));
+        assert_eq!(player.eliminated_turn, Some(6));
+        assert_eq!(player.time_bank, TimeDuration::from_micros(0));
+    }
+
+    #[test]
+    fn running_out_of_time_can_cost_a_life_and_refill_the_bank() {
+        let mut player = player_with_lives(1, 3);
+        player.handicap.extra_seconds = 15;
+        run_out_of_time(&mut player, clock(ClockExpiry::LoseLife), 6);
+        assert!(matches!(
+            player.win_condition_data,
+            PlayerWinConditionData::LastPlayerStanding { lives: 2 }
+        ));
+        assert_eq!(player.eliminated_turn, None);
+        assert_eq!(player.time_bank, seconds(75));
+    }
+
+    fn countdown_with(players: Vec<PlayerGameData>) -> GameState {
+        GameState::Countdown(CountdownState {
+            countdown_seconds: 5,
+            settings: SettingsState {
+                players,
+                ..default_settings()
+            },
+        })
+    }
+
+    #[test]
+    fn only_starting_games_can_be_cancelled() {
+        let countdown = countdown_with(vec![player_with_lives(1, 3), player_with_lives(2, 3)]);
+        assert_eq!(settings_before_start(&countdown).unwrap().players.len(), 2);
+        let ready_check = GameState::ReadyCheck(ReadyCheckState {
+            settings: default_settings(),
+            ready_players: Vec::new(),
+            started_at: Timestamp::UNIX_EPOCH,
+        });
+        assert!(settings_before_start(&ready_check).is_ok());
+        assert!(settings_before_start(&GameState::Settings(default_settings())).is_err());
+    }
+
+    #[test]
+    fn start_is_cancelled_once_fewer_than_two_players_are_connected() {
+        let state = countdown_with(vec![
+            player_with_lives(1, 3),
+            player_with_lives(2, 3),
+            player_with_lives(3, 3),
+        ]);
+        let online =
+            |players: Vec<u8>| move |id: Identity| players.iter().any(|n| identity(*n) == id);
+        assert!(!start_lacks_players(
+            &state,
+            identity(1),
+            online(vec![2, 3])
+        ));
+        assert!(start_lacks_players(&state, identity(1), online(vec![2])));
+        // A disconnect only affects games the player was starting
+        assert!(!start_lacks_players(&state, identity(9), online(vec![2])));
+        assert!(!start_lacks_players(
+            &GameState::Settings(default_settings()),
+            identity(1),
+            online(vec![])
+        ));
+    }
+
+    #[test]
+    fn rematch_needs_a_majority_of_at_least_two() {
+        assert!(!rematch_threshold_reached(1, 1));
+        assert!(!rematch_threshold_reached(1, 2));
+        assert!(rematch_threshold_reached(2, 2));
+        assert!(rematch_threshold_reached(2, 3));
+        assert!(!rematch_threshold_reached(2, 4));
+        assert!(rematch_threshold_reached(3, 4));
+    }
+
+    #[test]
+    fn rematch_keeps_only_voters_with_fresh_data() {
+        let mut voter = player_with_lives(1, 0);
+        voter.eliminated_turn = Some(4);
+        voter.handicap.extra_lives = 1;
+        let mut state = finished_state(
+            vec![voter, player_with_lives(2, 2), player_with_lives(3, 1)],
+            GameResult::Winner(identity(2)),
+        );
+        state.rematch_votes = vec![identity(3), identity(1)];
+
+        let settings = rematch_settings(&state);
+        let seated: Vec<Identity> = settings.players.iter().map(|p| p.player_identity).collect();
+        assert_eq!(seated, vec![identity(1), identity(3)]);
+        assert_eq!(settings.players[0].eliminated_turn, None);
+        assert!(matches!(
+            settings.players[0].win_condition_data,
+            PlayerWinConditionData::LastPlayerStanding { lives: 4 }
+        ));
+        assert_eq!(settings.players[0].handicap.extra_lives, 1);
+    }
+
+    fn best_of(best_of: u32) -> SeriesState {
+        new_series(&SettingsState {
+            series_best_of: Some(best_of),
+            ..default_settings()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn series_is_won_by_a_majority_of_games() {
+        let mut series = best_of(3);
+        record_series_game(&mut series, &GameResult::Winner(identity(1)));
+        record_series_game(&mut series, &GameResult::Winner(identity(2)));
+        assert_eq!(series.winner, None);
+        record_series_game(&mut series, &GameResult::Winner(identity(1)));
+        assert_eq!(series.winner, Some(identity(1)));
+        assert_eq!(series.games_played, 3);
+    }
+
+    #[test]
+    fn drawn_series_games_are_replayed() {
+        let mut series = best_of(1);
+        record_series_game(&mut series, &GameResult::Draw);
+        assert_eq!(series.winner, None);
+        assert!(series.wins.is_empty());
+        assert_eq!(series.games_played, 1);
+        record_series_game(&mut series, &GameResult::Winner(identity(2)));
+        assert_eq!(series.winner, Some(identity(2)));
+    }
+
+    #[test]
+    fn rematch_continues_an_undecided_series_with_the_same_players() {
+        let settings = SettingsState {
+            series_best_of: Some(5),
+            ..default_settings()
+        };
+        let mut series = best_of(5);
+        record_series_game(&mut series, &GameResult::Winner(identity(1)));
+
+        let continued = series_for_rematch(Some(series.clone()), &settings, false).unwrap();
+        assert_eq!(continued.games_played, 1);
+
+        let restarted = series_for_rematch(Some(series.clone()), &settings, true).unwrap();
+        assert_eq!(restarted.games_played, 0);
+
+        series.winner = Some(identity(1));
+        let restarted = series_for_rematch(Some(series), &settings, false).unwrap();
+        assert_eq!(restarted.games_played, 0);
+        assert_eq!(restarted.winner, None);
+    }
+
+    fn lobby_table(players: u8, max_players: u32, spectators: Vec<u8>) -> GameStateTable {
+        let settings = SettingsState {
+            players: (1..=players).map(|n| player_with_lives(n, 3)).collect(),
+            max_players,
+            ..default_settings()
+        };
+        GameStateTable {
+            state: GameState::Settings(settings),
+            spectators: spectators.into_iter().map(identity).collect(),
+            ..playing_table(finished_state(Vec::new(), GameResult::None))
+        }
+    }
+
+    fn seated(game_state: &GameStateTable) -> Vec<Identity> {
+        match &game_state.state {
+            GameState::Settings(settings) => {
+                settings.players.iter().map(|p| p.player_identity).collect()
+            }
+            _ => panic!("Game should be in the lobby"),
+        }
+    }
+
+    #[test]
+    fn open_seats_go_to_spectators_in_the_order_they_joined() {
+        let mut game_state = lobby_table(2, 4, vec![7, 5, 6]);
+        fill_open_seats(&mut game_state);
+        assert_eq!(
+            seated(&game_state),
+            vec![identity(1), identity(2), identity(7), identity(5)]
+        );
+        assert_eq!(game_state.spectators, vec![identity(6)]);
+    }
+
+    #[test]
+    fn full_lobbies_keep_their_spectators() {
+        let mut game_state = lobby_table(3, 3, vec![4]);
+        fill_open_seats(&mut game_state);
+        assert_eq!(seated(&game_state).len(), 3);
+        assert_eq!(game_state.spectators, vec![identity(4)]);
+    }
+
+    #[test]
+    fn seats_are_not_handed_out_during_a_game() {
+        let mut game_state = playing_table(finished_state(
+            vec![player_with_lives(1, 3)],
+            GameResult::None,
+        ));
+        game_state.spectators = vec![identity(2)];
+        fill_open_seats(&mut game_state);
+        assert_eq!(game_state.spectators, vec![identity(2)]);
+    }
+
+    fn react_at(
+        state: &mut PlayingState,
+        from: u8,
+        target: ReactionTarget,
+        millis: i64,
+    ) -> Result<(), String> {
+        add_reaction(
+            state,
+            identity(from),
+            false,
+            Emote::Clap,
+            target,
+            Timestamp::from_micros_since_unix_epoch(millis * 1_000),
+        )
+    }
+
+    #[test]
+    fn reactions_notify_only_their_target() {
+        let mut state = classic_state(vec![player_with_lives(1, 3), player_with_lives(2, 3)]);
+        react_at(&mut state, 1, ReactionTarget::Player(identity(2)), 0).unwrap();
+        assert!(state.players[0].events.is_empty());
+        assert!(matches!(
+            state.players[1].events.as_slice(),
+            [GameStateEvent::Reaction(_)]
+        ));
+        assert_eq!(state.reactions.len(), 1);
+    }
+
+    #[test]
+    fn reactions_need_a_guess_that_was_made() {
+        let mut state = classic_state(vec![player_with_lives(1, 3), player_with_lives(2, 3)]);
+        state.players[1].past_guesses.push(guess_answered_in(0));
+        let guess = |word: &str| {
+            ReactionTarget::Guess(ReactedGuess {
+                player_identity: identity(2),
+                word: word.to_string(),
+            })
+        };
+        assert!(react_at(&mut state, 1, guess("OTHER"), 0).is_err());
+        assert!(react_at(&mut state, 1, guess("WORD"), 0).is_ok());
+    }
+
+    #[test]
+    fn reactions_are_only_open_to_players_and_spectators() {
+        let mut state = classic_state(vec![player_with_lives(1, 3)]);
+        assert!(react_at(&mut state, 9, ReactionTarget::Player(identity(1)), 0).is_err());
+        assert!(add_reaction(
+            &mut state,
+            identity(9),
+            true,
+            Emote::Wow,
+            ReactionTarget::Player(identity(1)),
+            Timestamp::UNIX_EPOCH,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn reactions_respect_the_cooldown() {
+        let mut state = classic_state(vec![player_with_lives(1, 3), player_with_lives(2, 3)]);
+        let target = ReactionTarget::Player(identity(2));
+        react_at(&mut state, 1, target.clone(), 0).unwrap();
+        assert!(react_at(&mut state, 1, target.clone(), REACTION_COOLDOWN_MILLIS - 1).is_err());
+        assert!(react_at(&mut state, 2, ReactionTarget::Player(identity(1)), 1).is_ok());
+        assert!(react_at(&mut state, 1, target, REACTION_COOLDOWN_MILLIS).is_ok());
+    }
+
+    #[test]
+    fn only_the_latest_reactions_are_kept() {
+        let mut state = classic_state(vec![player_with_lives(1, 3), player_with_lives(2, 3)]);
+        for n in 0..MAX_STORED_REACTIONS as i64 + 5 {
+            react_at(
+                &mut state,
+                1,
+                ReactionTarget::Player(identity(2)),
+                n * REACTION_COOLDOWN_MILLIS,
+            )
+            .unwrap();
+        }
+        assert_eq!(state.reactions.len(), MAX_STORED_REACTIONS);
+        assert_eq!(
+            state.reactions[0].reacted_at,
+            Timestamp::from_micros_since_unix_epoch(5 * REACTION_COOLDOWN_MILLIS * 1_000)
+        );
+    }
+}
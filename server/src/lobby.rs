@@ -0,0 +1,151 @@
+use spacetimedb::{ReducerContext, SpacetimeType, Table};
+
+use crate::{
+    game, GameResult, GameState, GameStateTable, LobbyVisibility, TurnLogicMode, WinCondition,
+};
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum LobbyPhase {
+    Settings,
+    ReadyCheck,
+    Countdown,
+    Playing,
+    Finished,
+}
+
+// One small row per game so lobby lists don't need to subscribe to every game_state
+#[spacetimedb::table(name = lobby_summary, public)]
+#[derive(PartialEq)]
+pub struct LobbySummaryTable {
+    #[primary_key]
+    pub game_id: u32,
+    pub name: String,
+    pub phase: LobbyPhase,
+    pub win_condition: WinCondition,
+    pub turn_logic_mode: TurnLogicMode,
+    pub player_count: u32,
+    pub max_players: u32,
+    pub visibility: LobbyVisibility,
+}
+
+// Helper function to write a game's lobby summary from its current state. Most game
+// updates (typing, guesses) don't change the summary, so it is only written on a change.
+pub fn sync_lobby_summary(ctx: &ReducerContext, game_state: &GameStateTable) {
+    let name = match ctx.db.game().id().find(game_state.game_id) {
+        Some(game) => game.name,
+        None => return,
+    };
+    let summary = summarize(game_state.game_id, name, &game_state.state);
+    match ctx.db.lobby_summary().game_id().find(game_state.game_id) {
+        Some(existing) if existing == summary => {}
+        Some(_) => {
+            ctx.db.lobby_summary().game_id().update(summary);
+        }
+        None => {
+            ctx.db.lobby_summary().insert(summary);
+        }
+    }
+}
+
+// Helper function to build the lobby list row for a game in the given state
+fn summarize(game_id: u32, name: String, state: &GameState) -> LobbySummaryTable {
+    let (settings, phase, player_count) = match state {
+        GameState::Settings(settings) => (settings, LobbyPhase::Settings, settings.players.len()),
+        GameState::ReadyCheck(ready_check) => (
+            &ready_check.settings,
+            LobbyPhase::ReadyCheck,
+            ready_check.settings.players.len(),
+        ),
+        GameState::Countdown(countdown_state) => (
+            &countdown_state.settings,
+            LobbyPhase::Countdown,
+            countdown_state.settings.players.len(),
+        ),
+        GameState::Playing(playing_state) => {
+            let phase = match playing_state.winner {
+                GameResult::None => LobbyPhase::Playing,
+                GameResult::Winner(_) | GameResult::Draw => LobbyPhase::Finished,
+            };
+            (&playing_state.settings, phase, playing_state.players.len())
+        }
+    };
+    LobbySummaryTable {
+        game_id,
+        name,
+        phase,
+        win_condition: settings.win_condition,
+        turn_logic_mode: settings.turn_logic_mode,
+        player_count: player_count as u32,
+        max_players: settings.max_players,
+        visibility: settings.visibility,
+    }
+}
+
+// Helper function to drop a deleted game from the lobby list
+pub fn remove_lobby_summary(ctx: &ReducerContext, game_id: u32) {
+    ctx.db.lobby_summary().game_id().delete(game_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        create_initial_player_game_data, default_settings, CountdownState, ReadyCheckState,
+        SettingsState, NO_HANDICAP,
+    };
+    use spacetimedb::{Identity, Timestamp};
+
+    fn settings_with_players(count: u8) -> SettingsState {
+        let win_condition = default_settings().win_condition;
+        SettingsState {
+            players: (1..=count)
+                .map(|n| {
+                    create_initial_player_game_data(
+                        Identity::from_byte_array([n; 32]),
+                        &win_condition,
+                        NO_HANDICAP,
+                    )
+                })
+                .collect(),
+            ..default_settings()
+        }
+    }
+
+    #[test]
+    fn summary_reflects_the_lobby() {
+        let summary = summarize(
+            7,
+            "Word nerds".to_string(),
+            &GameState::Settings(settings_with_players(3)),
+        );
+        assert_eq!(summary.game_id, 7);
+        assert_eq!(summary.name, "Word nerds");
+        assert!(summary.phase == LobbyPhase::Settings);
+        assert_eq!(summary.player_count, 3);
+        assert_eq!(summary.max_players, default_settings().max_players);
+    }
+
+    #[test]
+    fn summary_follows_the_game_phase() {
+        let countdown = GameState::Countdown(CountdownState {
+            countdown_seconds: 5,
+            settings: settings_with_players(2),
+        });
+        assert!(summarize(1, String::new(), &countdown).phase == LobbyPhase::Countdown);
+        let ready_check = GameState::ReadyCheck(ReadyCheckState {
+            settings: settings_with_players(2),
+            ready_players: Vec::new(),
+            started_at: Timestamp::UNIX_EPOCH,
+        });
+        assert!(summarize(1, String::new(), &ready_check).phase == LobbyPhase::ReadyCheck);
+    }
+
+    #[test]
+    fn summary_only_changes_when_the_lobby_does() {
+        let state = GameState::Settings(settings_with_players(2));
+        let summary = summarize(1, "Game".to_string(), &state);
+        assert!(summary == summarize(1, "Game".to_string(), &state));
+        let joined = GameState::Settings(settings_with_players(3));
+        assert!(summary != summarize(1, "Game".to_string(), &joined));
+    }
+}
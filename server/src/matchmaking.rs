@@ -0,0 +1,353 @@
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+
+use crate::rating::rating_for;
+use crate::{
+    add_identity_to_game, default_settings, insert_game, player_info, start_game, SettingsState,
+    TurnLogicMode,
+};
+
+const RANKED_MIN_PLAYERS: usize = 2;
+const RANKED_MAX_PLAYERS: usize = 4;
+// How long the longest waiting player waits for a full game before a smaller one is started
+const RANKED_FULL_GAME_WAIT_SECONDS: i64 = 20;
+// Rating window around the longest waiting player, widened the longer they wait
+const RANKED_BASE_RATING_SPREAD: i64 = 100;
+const RANKED_SPREAD_PER_SECOND_WAITED: i64 = 5;
+const RANKED_MAX_RATING_SPREAD: i64 = 400;
+const RANKED_MATCHMAKING_INTERVAL_SECONDS: i64 = 5;
+const QUICK_PLAY_MIN_PLAYERS: usize = 2;
+const QUICK_PLAY_MAX_PLAYERS: usize = 6;
+// How long the longest waiting player waits for a full game before a smaller one is started
+const QUICK_PLAY_FULL_GAME_WAIT_SECONDS: i64 = 10;
+const QUICK_PLAY_MATCHMAKING_INTERVAL_SECONDS: i64 = 5;
+
+#[spacetimedb::table(name = ranked_queue, public)]
+pub struct RankedQueueTable {
+    #[primary_key]
+    pub identity: Identity,
+    pub rating: i32,
+    pub joined_at: Timestamp,
+}
+
+#[spacetimedb::table(name = ranked_matchmaking_schedule, scheduled(ranked_matchmaking))]
+pub struct RankedMatchmakingSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+// Helper function to start the recurring ranked matchmaker
+pub fn schedule_ranked_matchmaking(ctx: &ReducerContext) {
+    let interval_micros = RANKED_MATCHMAKING_INTERVAL_SECONDS * 1_000_000;
+    ctx.db
+        .ranked_matchmaking_schedule()
+        .insert(RankedMatchmakingSchedule {
+            scheduled_id: 0, // Auto-incremented
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(interval_micros)),
+        });
+}
+
+// Helper function to take a player out of the ranked queue if they are in it
+pub fn remove_from_ranked_queue(ctx: &ReducerContext, identity: Identity) {
+    ctx.db.ranked_queue().identity().delete(identity);
+}
+
+#[spacetimedb::reducer]
+pub fn join_ranked_queue(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.player_info().identity().find(ctx.sender).is_none() {
+        return Err("Player must be registered to join the ranked queue".to_string());
+    }
+    if ctx.db.ranked_queue().identity().find(ctx.sender).is_some() {
+        return Err("Already in the ranked queue".to_string());
+    }
+    if ctx
+        .db
+        .quick_play_queue()
+        .identity()
+        .find(ctx.sender)
+        .is_some()
+    {
+        return Err("Leave the quick play queue before joining ranked".to_string());
+    }
+    ctx.db.ranked_queue().insert(RankedQueueTable {
+        identity: ctx.sender,
+        rating: rating_for(ctx, ctx.sender),
+        joined_at: ctx.timestamp,
+    });
+    assemble_ranked_games(ctx)
+}
+
+#[spacetimedb::reducer]
+pub fn leave_ranked_queue(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.ranked_queue().identity().find(ctx.sender).is_none() {
+        return Err("Not in the ranked queue".to_string());
+    }
+    remove_from_ranked_queue(ctx, ctx.sender);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn ranked_matchmaking(
+    ctx: &ReducerContext,
+    _arg: RankedMatchmakingSchedule,
+) -> Result<(), String> {
+    assemble_ranked_games(ctx)
+}
+
+// Helper function to get how long a queued player has been waiting
+fn seconds_waited(ctx: &ReducerContext, entry: &RankedQueueTable) -> i64 {
+    (ctx.timestamp.to_micros_since_unix_epoch() - entry.joined_at.to_micros_since_unix_epoch())
+        / 1_000_000
+}
+
+// Starts games for queued players of similar rating. The longest waiting player anchors
+// each game and is grouped with the closest rated players inside their rating window.
+fn assemble_ranked_games(ctx: &ReducerContext) -> Result<(), String> {
+    let mut queue: Vec<RankedQueueTable> = ctx.db.ranked_queue().iter().collect();
+    queue.sort_by_key(|entry| entry.joined_at);
+
+    let mut anchor_index = 0;
+    while anchor_index < queue.len() {
+        let anchor = &queue[anchor_index];
+        let waited = seconds_waited(ctx, anchor);
+        let spread = (RANKED_BASE_RATING_SPREAD + waited * RANKED_SPREAD_PER_SECOND_WAITED)
+            .min(RANKED_MAX_RATING_SPREAD);
+
+        let mut candidates: Vec<&RankedQueueTable> = queue
+            .iter()
+            .filter(|entry| ((entry.rating - anchor.rating) as i64).abs() <= spread)
+            .collect();
+        candidates.sort_by_key(|entry| (entry.rating - anchor.rating).abs());
+        candidates.truncate(RANKED_MAX_PLAYERS);
+
+        let ready = candidates.len() == RANKED_MAX_PLAYERS
+            || (candidates.len() >= RANKED_MIN_PLAYERS && waited >= RANKED_FULL_GAME_WAIT_SECONDS);
+        if !ready {
+            anchor_index += 1;
+            continue;
+        }
+
+        let matched: Vec<Identity> = candidates.iter().map(|entry| entry.identity).collect();
+        start_ranked_game(ctx, &matched)?;
+        queue.retain(|entry| !matched.contains(&entry.identity));
+    }
+    Ok(())
+}
+
+// Helper function to create, seat and start a ranked game
+fn start_ranked_game(ctx: &ReducerContext, players: &[Identity]) -> Result<(), String> {
+    let game = insert_game(ctx, "Ranked match".to_string(), default_settings(), true);
+    for player in players {
+        remove_from_ranked_queue(ctx, *player);
+        add_identity_to_game(ctx, game.id, *player)?;
+    }
+    start_game(ctx, game.id)
+}
+
+#[spacetimedb::table(name = quick_play_queue, public)]
+pub struct QuickPlayQueueTable {
+    #[primary_key]
+    pub identity: Identity,
+    pub preferred_mode: Option<TurnLogicMode>, // None plays either mode
+    pub joined_at: Timestamp,
+}
+
+#[spacetimedb::table(name = quick_play_schedule, scheduled(quick_play_matchmaking))]
+pub struct QuickPlaySchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+// Helper function to start the recurring quick play matchmaker
+pub fn schedule_quick_play_matchmaking(ctx: &ReducerContext) {
+    let interval_micros = QUICK_PLAY_MATCHMAKING_INTERVAL_SECONDS * 1_000_000;
+    ctx.db.quick_play_schedule().insert(QuickPlaySchedule {
+        scheduled_id: 0, // Auto-incremented
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(interval_micros)),
+    });
+}
+
+// Helper function to take a player out of the quick play queue if they are in it
+pub fn remove_from_quick_play_queue(ctx: &ReducerContext, identity: Identity) {
+    ctx.db.quick_play_queue().identity().delete(identity);
+}
+
+#[spacetimedb::reducer]
+pub fn join_quick_play_queue(
+    ctx: &ReducerContext,
+    preferred_mode: Option<TurnLogicMode>,
+) -> Result<(), String> {
+    if ctx.db.player_info().identity().find(ctx.sender).is_none() {
+        return Err("Player must be registered to join quick play".to_string());
+    }
+    if ctx
+        .db
+        .quick_play_queue()
+        .identity()
+        .find(ctx.sender)
+        .is_some()
+    {
+        return Err("Already in the quick play queue".to_string());
+    }
+    if ctx.db.ranked_queue().identity().find(ctx.sender).is_some() {
+        return Err("Leave the ranked queue before joining quick play".to_string());
+    }
+    ctx.db.quick_play_queue().insert(QuickPlayQueueTable {
+        identity: ctx.sender,
+        preferred_mode,
+        joined_at: ctx.timestamp,
+    });
+    assemble_quick_play_games(ctx)
+}
+
+#[spacetimedb::reducer]
+pub fn leave_quick_play_queue(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx
+        .db
+        .quick_play_queue()
+        .identity()
+        .find(ctx.sender)
+        .is_none()
+    {
+        return Err("Not in the quick play queue".to_string());
+    }
+    remove_from_quick_play_queue(ctx, ctx.sender);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn quick_play_matchmaking(ctx: &ReducerContext, _arg: QuickPlaySchedule) -> Result<(), String> {
+    assemble_quick_play_games(ctx)
+}
+
+// Starts a game for each mode once enough players who will play it are waiting. A full game
+// starts straight away, a smaller one once its longest waiting player has waited long enough.
+fn assemble_quick_play_games(ctx: &ReducerContext) -> Result<(), String> {
+    let mut queue: Vec<QuickPlayQueueTable> = ctx.db.quick_play_queue().iter().collect();
+    queue.sort_by_key(|entry| entry.joined_at);
+
+    for mode in [TurnLogicMode::Classic, TurnLogicMode::Simultaneous] {
+        loop {
+            let candidates = quick_play_candidates(&queue, mode);
+            let oldest_waited = candidates
+                .first()
+                .map(|entry| {
+                    (ctx.timestamp.to_micros_since_unix_epoch()
+                        - entry.joined_at.to_micros_since_unix_epoch())
+                        / 1_000_000
+                })
+                .unwrap_or(0);
+            if !quick_play_ready(candidates.len(), oldest_waited) {
+                break;
+            }
+
+            let matched: Vec<Identity> = candidates.iter().map(|entry| entry.identity).collect();
+            start_quick_play_game(ctx, mode, &matched)?;
+            queue.retain(|entry| !matched.contains(&entry.identity));
+        }
+    }
+    Ok(())
+}
+
+// Helper function to pick the longest waiting players who will play a mode, up to a full
+// game. The queue must be sorted by join time.
+fn quick_play_candidates(
+    queue: &[QuickPlayQueueTable],
+    mode: TurnLogicMode,
+) -> Vec<&QuickPlayQueueTable> {
+    queue
+        .iter()
+        .filter(|entry| match entry.preferred_mode {
+            None => true,
+            Some(preferred) => preferred == mode,
+        })
+        .take(QUICK_PLAY_MAX_PLAYERS)
+        .collect()
+}
+
+// Helper function to check whether a quick play group should start now: straight away when
+// full, or once its longest waiting player has waited long enough for a smaller game
+fn quick_play_ready(player_count: usize, oldest_waited_seconds: i64) -> bool {
+    player_count == QUICK_PLAY_MAX_PLAYERS
+        || (player_count >= QUICK_PLAY_MIN_PLAYERS
+            && oldest_waited_seconds >= QUICK_PLAY_FULL_GAME_WAIT_SECONDS)
+}
+
+// Helper function to create, seat and start a quick play game
+fn start_quick_play_game(
+    ctx: &ReducerContext,
+    mode: TurnLogicMode,
+    players: &[Identity],
+) -> Result<(), String> {
+    let settings = SettingsState {
+        turn_logic_mode: mode,
+        ..default_settings()
+    };
+    let game = insert_game(ctx, "Quick play".to_string(), settings, false);
+    for player in players {
+        remove_from_quick_play_queue(ctx, *player);
+        add_identity_to_game(ctx, game.id, *player)?;
+    }
+    start_game(ctx, game.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queued(n: u8, preferred_mode: Option<TurnLogicMode>) -> QuickPlayQueueTable {
+        QuickPlayQueueTable {
+            identity: Identity::from_byte_array([n; 32]),
+            preferred_mode,
+            joined_at: Timestamp::from_micros_since_unix_epoch(n as i64),
+        }
+    }
+
+    fn numbers(candidates: &[&QuickPlayQueueTable]) -> Vec<u8> {
+        candidates
+            .iter()
+            .map(|entry| entry.identity.to_byte_array()[0])
+            .collect()
+    }
+
+    #[test]
+    fn quick_play_matches_players_who_will_play_the_mode() {
+        let queue = vec![
+            queued(1, Some(TurnLogicMode::Classic)),
+            queued(2, None),
+            queued(3, Some(TurnLogicMode::Simultaneous)),
+            queued(4, Some(TurnLogicMode::Classic)),
+        ];
+        assert_eq!(
+            numbers(&quick_play_candidates(&queue, TurnLogicMode::Classic)),
+            vec![1, 2, 4]
+        );
+        assert_eq!(
+            numbers(&quick_play_candidates(&queue, TurnLogicMode::Simultaneous)),
+            vec![2, 3]
+        );
+    }
+
+    #[test]
+    fn quick_play_takes_the_longest_waiting_players_first() {
+        let queue: Vec<QuickPlayQueueTable> = (1..=8).map(|n| queued(n, None)).collect();
+        assert_eq!(
+            numbers(&quick_play_candidates(&queue, TurnLogicMode::Classic)),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn quick_play_starts_full_games_at_once_and_small_ones_after_a_wait() {
+        assert!(quick_play_ready(QUICK_PLAY_MAX_PLAYERS, 0));
+        assert!(!quick_play_ready(QUICK_PLAY_MIN_PLAYERS, 0));
+        assert!(quick_play_ready(
+            QUICK_PLAY_MIN_PLAYERS,
+            QUICK_PLAY_FULL_GAME_WAIT_SECONDS
+        ));
+        assert!(!quick_play_ready(1, QUICK_PLAY_FULL_GAME_WAIT_SECONDS * 10));
+    }
+}
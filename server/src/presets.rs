@@ -0,0 +1,107 @@
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+use crate::{get_game_state, update_settings, GameState, SettingsState};
+
+const MAX_PRESET_NAME_LENGTH: usize = 32;
+
+// A player's saved game settings. The players list is always stored empty.
+#[spacetimedb::table(name = settings_preset, public)]
+pub struct SettingsPresetTable {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    #[index(btree)]
+    pub owner: Identity,
+    pub name: String,
+    pub settings: SettingsState,
+    pub created_at: Timestamp,
+}
+
+// Helper function to look up the settings saved in a preset
+pub fn preset_settings(ctx: &ReducerContext, preset_id: u32) -> Result<SettingsState, String> {
+    ctx.db
+        .settings_preset()
+        .id()
+        .find(preset_id)
+        .map(|preset| preset.settings)
+        .ok_or("Preset not found".to_string())
+}
+
+#[spacetimedb::reducer]
+pub fn save_settings_preset(
+    ctx: &ReducerContext,
+    game_id: u32,
+    name: String,
+) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Preset name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAX_PRESET_NAME_LENGTH {
+        return Err(format!(
+            "Preset name can be at most {} characters",
+            MAX_PRESET_NAME_LENGTH
+        ));
+    }
+    let game_state = get_game_state(ctx, game_id).ok_or("Game not initialized")?;
+    let settings = match game_state.state {
+        GameState::Settings(settings) => settings,
+        _ => return Err("Can only save a preset from Settings state".to_string()),
+    };
+
+    // Saving under an existing name replaces that preset
+    let existing = ctx
+        .db
+        .settings_preset()
+        .owner()
+        .filter(ctx.sender)
+        .find(|preset| preset.name == name);
+    let preset = SettingsPresetTable {
+        id: existing.as_ref().map(|preset| preset.id).unwrap_or(0), // Auto-incremented
+        owner: ctx.sender,
+        name,
+        settings: SettingsState {
+            players: Vec::new(),
+            ..settings
+        },
+        created_at: ctx.timestamp,
+    };
+    if existing.is_some() {
+        ctx.db.settings_preset().id().update(preset);
+    } else {
+        ctx.db.settings_preset().insert(preset);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn delete_settings_preset(ctx: &ReducerContext, preset_id: u32) -> Result<(), String> {
+    let preset = ctx
+        .db
+        .settings_preset()
+        .id()
+        .find(preset_id)
+        .ok_or("Preset not found")?;
+    if preset.owner != ctx.sender {
+        return Err("Can only delete your own presets".to_string());
+    }
+    ctx.db.settings_preset().id().delete(preset_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn apply_settings_preset(
+    ctx: &ReducerContext,
+    game_id: u32,
+    preset_id: u32,
+) -> Result<(), String> {
+    let preset = preset_settings(ctx, preset_id)?;
+    update_settings(ctx, game_id, "settings preset", |settings| {
+        // Keep the seated players, update_settings rebuilds them for the preset
+        *settings = SettingsState {
+            players: std::mem::take(&mut settings.players),
+            ..preset
+        };
+        Ok(())
+    })
+}
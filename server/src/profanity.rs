@@ -0,0 +1,84 @@
+// Terms that may not appear inside any word of a username. Names are split into words
+// at anything that isn't a letter and each word is compared lowercased.
+const BLOCKED_TERMS: &[&str] = &[
+    "asshole",
+    "bastard",
+    "bitch",
+    "bollocks",
+    "cunt",
+    "dickhead",
+    "fag",
+    "fuck",
+    "motherfucker",
+    "nazi",
+    "nigga",
+    "nigger",
+    "penis",
+    "porn",
+    "pussy",
+    "retard",
+    "shit",
+    "slut",
+    "twat",
+    "vagina",
+    "wank",
+    "whore",
+];
+
+// Real names and words that happen to contain a blocked term
+const ALLOWED_WORDS: &[&str] = &[
+    "fagan",
+    "fagin",
+    "nazia",
+    "nazir",
+    "nazira",
+    "penistone",
+    "retardant",
+    "scunthorpe",
+    "shitake",
+    "swank",
+    "swanky",
+    "wankel",
+];
+
+// Helper function to check a name against the blocked terms, one word at a time
+pub fn contains_profanity(name: &str) -> bool {
+    name.split(|c: char| !c.is_ascii_alphabetic())
+        .map(|word| word.to_ascii_lowercase())
+        .filter(|word| !ALLOWED_WORDS.contains(&word.as_str()))
+        .any(|word| BLOCKED_TERMS.iter().any(|term| word.contains(term)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks_terms_inside_a_word() {
+        assert!(contains_profanity("shit"));
+        assert!(contains_profanity("ShitHead"));
+        assert!(contains_profanity("big_fucker"));
+        assert!(contains_profanity("xX-Twat-Xx"));
+    }
+
+    #[test]
+    fn allows_clean_names() {
+        assert!(!contains_profanity("Player One"));
+        assert!(!contains_profanity("bomb_master.99"));
+    }
+
+    #[test]
+    fn allows_known_false_positives() {
+        assert!(!contains_profanity("Nazir"));
+        assert!(!contains_profanity("Fagan"));
+        assert!(!contains_profanity("Scunthorpe"));
+        assert!(!contains_profanity("Penistone FC"));
+        assert!(!contains_profanity("swanky"));
+    }
+
+    #[test]
+    fn does_not_match_across_words() {
+        assert!(!contains_profanity("Kim Ash Itoh"));
+        assert!(!contains_profanity("Ida Mn_Fuc.K"));
+    }
+}
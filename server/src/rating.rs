@@ -0,0 +1,142 @@
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use std::cmp::Ordering;
+
+pub const DEFAULT_RATING: i32 = 1200;
+const K_FACTOR: f64 = 32.0;
+
+#[spacetimedb::table(name = leaderboard, public)]
+pub struct LeaderboardTable {
+    #[primary_key]
+    pub identity: Identity,
+    #[index(btree)]
+    pub rating: i32,
+    pub games_played: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub updated_at: Timestamp,
+}
+
+// Helper function to get a player's rating, defaulting for unrated players
+pub fn rating_for(ctx: &ReducerContext, identity: Identity) -> i32 {
+    ctx.db
+        .leaderboard()
+        .identity()
+        .find(identity)
+        .map(|entry| entry.rating)
+        .unwrap_or(DEFAULT_RATING)
+}
+
+// Elo expected score of a player against a single opponent
+fn expected_score(rating: f64, opponent_rating: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent_rating - rating) / 400.0))
+}
+
+// Works out everyone's new rating from their placements (1 is best, equal placements are
+// draws). Every player is scored pairwise against every other player and the K factor is
+// split across opponents so a game moves a rating about as much as a 1v1 would.
+fn updated_ratings(ratings: &[i32], placements: &[u32]) -> Vec<i32> {
+    let k = K_FACTOR / (placements.len().max(2) - 1) as f64;
+    placements
+        .iter()
+        .enumerate()
+        .map(|(i, placement)| {
+            let score_delta: f64 = placements
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(j, other_placement)| {
+                    let actual = match placement.cmp(other_placement) {
+                        Ordering::Less => 1.0,
+                        Ordering::Equal => 0.5,
+                        Ordering::Greater => 0.0,
+                    };
+                    actual - expected_score(ratings[i] as f64, ratings[j] as f64)
+                })
+                .sum();
+            (ratings[i] as f64 + k * score_delta).round() as i32
+        })
+        .collect()
+}
+
+// Updates ratings and win/draw/loss counts from a finished game's placements
+pub fn record_placements(ctx: &ReducerContext, placements: &[(Identity, u32)]) {
+    if placements.len() < 2 {
+        return;
+    }
+    let ratings: Vec<i32> = placements
+        .iter()
+        .map(|(identity, _)| rating_for(ctx, *identity))
+        .collect();
+    let places: Vec<u32> = placements.iter().map(|(_, placement)| *placement).collect();
+    let new_ratings = updated_ratings(&ratings, &places);
+
+    for (i, (identity, placement)) in placements.iter().enumerate() {
+        let rating = new_ratings[i];
+        let shares_placement = placements
+            .iter()
+            .enumerate()
+            .any(|(j, (_, other_placement))| j != i && other_placement == placement);
+
+        let existing = ctx.db.leaderboard().identity().find(*identity);
+        let is_new = existing.is_none();
+        let mut entry = existing.unwrap_or(LeaderboardTable {
+            identity: *identity,
+            rating: DEFAULT_RATING,
+            games_played: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            updated_at: ctx.timestamp,
+        });
+        entry.rating = rating;
+        entry.games_played += 1;
+        match (*placement, shares_placement) {
+            (1, false) => entry.wins += 1,
+            (1, true) => entry.draws += 1,
+            _ => entry.losses += 1,
+        }
+        entry.updated_at = ctx.timestamp;
+
+        if is_new {
+            ctx.db.leaderboard().insert(entry);
+        } else {
+            ctx.db.leaderboard().identity().update(entry);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn even_one_on_one_moves_half_the_k_factor() {
+        assert_eq!(updated_ratings(&[1200, 1200], &[1, 2]), vec![1216, 1184]);
+    }
+
+    #[test]
+    fn even_draw_leaves_ratings_unchanged() {
+        assert_eq!(updated_ratings(&[1200, 1200], &[1, 1]), vec![1200, 1200]);
+    }
+
+    #[test]
+    fn favourite_gains_less_than_an_upset_would() {
+        assert_eq!(updated_ratings(&[1400, 1200], &[1, 2]), vec![1408, 1192]);
+        assert_eq!(updated_ratings(&[1400, 1200], &[2, 1]), vec![1376, 1224]);
+    }
+
+    #[test]
+    fn k_factor_is_split_across_opponents() {
+        assert_eq!(
+            updated_ratings(&[1200, 1200, 1200], &[1, 2, 3]),
+            vec![1216, 1200, 1184]
+        );
+    }
+
+    #[test]
+    fn expected_scores_sum_to_one() {
+        let total = expected_score(1500.0, 1300.0) + expected_score(1300.0, 1500.0);
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+}
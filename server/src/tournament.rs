@@ -0,0 +1,664 @@
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+
+use crate::presets::preset_settings;
+use crate::rating::rating_for;
+use crate::validation::validate_settings;
+use crate::{
+    add_identity_to_game, default_settings, insert_game, player_info, start_game, GameResult,
+    SettingsState,
+};
+
+const MAX_TOURNAMENT_NAME_LENGTH: usize = 48;
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum TournamentFormat {
+    SingleElimination,
+    RoundRobin,
+}
+
+#[derive(Clone, Copy, SpacetimeType, PartialEq)]
+pub enum TournamentStatus {
+    Registration,
+    InProgress,
+    Finished,
+}
+
+#[spacetimedb::table(name = tournament, public)]
+#[derive(Clone)]
+pub struct TournamentTable {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub name: String,
+    pub organizer: Identity,
+    pub format: TournamentFormat,
+    pub status: TournamentStatus,
+    pub settings: SettingsState, // Every match game is created with these settings
+    pub current_round: u32,
+    pub winner: Option<Identity>,
+    pub created_at: Timestamp,
+}
+
+#[spacetimedb::table(name = tournament_participant, public)]
+#[derive(Clone)]
+pub struct TournamentParticipantTable {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    #[index(btree)]
+    pub tournament_id: u32,
+    pub identity: Identity,
+    pub seed: Option<u32>, // 1 is the highest rated, set when the tournament is seeded
+    pub wins: u32,
+}
+
+#[spacetimedb::table(name = tournament_match, public)]
+#[derive(Clone)]
+pub struct TournamentMatchTable {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    #[index(btree)]
+    pub tournament_id: u32,
+    pub round: u32,
+    pub position: u32, // Order of the match within its round
+    pub player_one: Option<Identity>,
+    pub player_two: Option<Identity>, // A bye in the first elimination round, otherwise not decided yet
+    pub game_id: Option<u32>,         // The game currently being played for this match
+    pub winner: Option<Identity>,
+    pub completed: bool,
+}
+
+#[spacetimedb::reducer]
+pub fn create_tournament(
+    ctx: &ReducerContext,
+    name: String,
+    format: TournamentFormat,
+    preset_id: Option<u32>,
+) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Tournament name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAX_TOURNAMENT_NAME_LENGTH {
+        return Err(format!(
+            "Tournament name can be at most {} characters",
+            MAX_TOURNAMENT_NAME_LENGTH
+        ));
+    }
+    let settings = match preset_id {
+        Some(preset_id) => preset_settings(ctx, preset_id)?,
+        None => default_settings(),
+    };
+    // Players are seated by the bracket, so nobody needs to confirm they're ready
+    let settings = SettingsState {
+        players: Vec::new(),
+        ready_check_seconds: None,
+        ..settings
+    };
+    validate_settings(&settings).map_err(|e| e.to_string())?;
+    ctx.db.tournament().insert(TournamentTable {
+        id: 0, // Auto-incremented
+        name,
+        organizer: ctx.sender,
+        format,
+        status: TournamentStatus::Registration,
+        settings,
+        current_round: 0,
+        winner: None,
+        created_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn register_for_tournament(ctx: &ReducerContext, tournament_id: u32) -> Result<(), String> {
+    if ctx.db.player_info().identity().find(ctx.sender).is_none() {
+        return Err("Player must be registered to join a tournament".to_string());
+    }
+    let tournament = find_tournament(ctx, tournament_id)?;
+    if tournament.status != TournamentStatus::Registration {
+        return Err("Tournament registration is closed".to_string());
+    }
+    if participants(ctx, tournament_id)
+        .iter()
+        .any(|p| p.identity == ctx.sender)
+    {
+        return Err("Already registered for this tournament".to_string());
+    }
+    ctx.db
+        .tournament_participant()
+        .insert(TournamentParticipantTable {
+            id: 0, // Auto-incremented
+            tournament_id,
+            identity: ctx.sender,
+            seed: None,
+            wins: 0,
+        });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn withdraw_from_tournament(ctx: &ReducerContext, tournament_id: u32) -> Result<(), String> {
+    let tournament = find_tournament(ctx, tournament_id)?;
+    if tournament.status != TournamentStatus::Registration {
+        return Err("Can only withdraw before the tournament starts".to_string());
+    }
+    let participant = participants(ctx, tournament_id)
+        .into_iter()
+        .find(|p| p.identity == ctx.sender)
+        .ok_or("Not registered for this tournament")?;
+    ctx.db.tournament_participant().id().delete(participant.id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn seed_tournament(ctx: &ReducerContext, tournament_id: u32) -> Result<(), String> {
+    let tournament = find_organized_tournament(ctx, tournament_id)?;
+    if tournament.status != TournamentStatus::Registration {
+        return Err("Can only seed a tournament before it starts".to_string());
+    }
+    assign_seeds(ctx, tournament_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn start_tournament(ctx: &ReducerContext, tournament_id: u32) -> Result<(), String> {
+    let mut tournament = find_organized_tournament(ctx, tournament_id)?;
+    if tournament.status != TournamentStatus::Registration {
+        return Err("Tournament has already started".to_string());
+    }
+    // Seed again so late registrations are placed by rating too
+    let seeded = assign_seeds(ctx, tournament_id);
+    if seeded.len() < 2 {
+        return Err("A tournament needs at least 2 participants".to_string());
+    }
+
+    tournament.status = TournamentStatus::InProgress;
+    tournament.current_round = 1;
+    ctx.db.tournament().id().update(tournament.clone());
+    match tournament.format {
+        TournamentFormat::SingleElimination => {
+            create_elimination_bracket(ctx, &tournament, &seeded)
+        }
+        TournamentFormat::RoundRobin => {
+            create_round_robin_schedule(ctx, &tournament, &seeded);
+            start_round_robin_round(ctx, &tournament, 1)
+        }
+    }
+}
+
+// Records the result of a finished game if it was played for a tournament match. Elimination
+// draws are replayed, round robin draws are recorded without a winner.
+pub fn record_game_result(
+    ctx: &ReducerContext,
+    game_id: u32,
+    result: &GameResult,
+) -> Result<(), String> {
+    let mut tournament_match = match ctx
+        .db
+        .tournament_match()
+        .iter()
+        .find(|m| m.game_id == Some(game_id) && !m.completed)
+    {
+        Some(tournament_match) => tournament_match,
+        None => return Ok(()),
+    };
+    let tournament = find_tournament(ctx, tournament_match.tournament_id)?;
+
+    match (result, tournament.format) {
+        (GameResult::None, _) => Ok(()),
+        (GameResult::Draw, TournamentFormat::SingleElimination) => {
+            spawn_match_game(ctx, &tournament, &mut tournament_match)
+        }
+        (GameResult::Draw, TournamentFormat::RoundRobin) => {
+            tournament_match.completed = true;
+            ctx.db
+                .tournament_match()
+                .id()
+                .update(tournament_match.clone());
+            finish_round_robin_match(ctx, &tournament, &tournament_match)
+        }
+        (GameResult::Winner(winner), format) => {
+            tournament_match.winner = Some(*winner);
+            tournament_match.completed = true;
+            ctx.db
+                .tournament_match()
+                .id()
+                .update(tournament_match.clone());
+            if let Some(mut participant) = participants(ctx, tournament.id)
+                .into_iter()
+                .find(|p| p.identity == *winner)
+            {
+                participant.wins += 1;
+                ctx.db.tournament_participant().id().update(participant);
+            }
+            match format {
+                TournamentFormat::SingleElimination => {
+                    advance_elimination_winner(ctx, &tournament, &tournament_match, *winner)
+                }
+                TournamentFormat::RoundRobin => {
+                    finish_round_robin_match(ctx, &tournament, &tournament_match)
+                }
+            }
+        }
+    }
+}
+
+// Resolves the match a game was played for when that game is deleted or abandoned, so the
+// tournament doesn't wait on it forever. A player who is still online wins by forfeit.
+// Otherwise elimination advances the better seed and round robin records no winner.
+pub fn forfeit_match_game(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
+    let tournament_match = match ctx
+        .db
+        .tournament_match()
+        .iter()
+        .find(|m| m.game_id == Some(game_id) && !m.completed)
+    {
+        Some(tournament_match) => tournament_match,
+        None => return Ok(()),
+    };
+    let tournament = find_tournament(ctx, tournament_match.tournament_id)?;
+    let players: Vec<Identity> = [tournament_match.player_one, tournament_match.player_two]
+        .into_iter()
+        .flatten()
+        .collect();
+    let online: Vec<Identity> = players
+        .iter()
+        .copied()
+        .filter(|identity| {
+            ctx.db
+                .player_info()
+                .identity()
+                .find(*identity)
+                .is_some_and(|info| info.is_online)
+        })
+        .collect();
+    let result = match (online.as_slice(), tournament.format) {
+        ([winner], _) => GameResult::Winner(*winner),
+        (_, TournamentFormat::SingleElimination) => {
+            let seeds = participants(ctx, tournament.id);
+            let seed_of = |identity: &Identity| {
+                seeds
+                    .iter()
+                    .find(|p| p.identity == *identity)
+                    .and_then(|p| p.seed)
+                    .unwrap_or(u32::MAX)
+            };
+            match players.iter().min_by_key(|identity| seed_of(identity)) {
+                Some(winner) => GameResult::Winner(*winner),
+                None => return Err("Tournament match is missing a player".to_string()),
+            }
+        }
+        (_, TournamentFormat::RoundRobin) => GameResult::Draw,
+    };
+    record_game_result(ctx, game_id, &result)
+}
+
+// Helper function to look up a tournament
+fn find_tournament(ctx: &ReducerContext, tournament_id: u32) -> Result<TournamentTable, String> {
+    ctx.db
+        .tournament()
+        .id()
+        .find(tournament_id)
+        .ok_or("Tournament not found".to_string())
+}
+
+// Helper function to look up a tournament the caller organizes
+fn find_organized_tournament(
+    ctx: &ReducerContext,
+    tournament_id: u32,
+) -> Result<TournamentTable, String> {
+    let tournament = find_tournament(ctx, tournament_id)?;
+    if tournament.organizer != ctx.sender {
+        return Err("Only the organizer can do that".to_string());
+    }
+    Ok(tournament)
+}
+
+fn participants(ctx: &ReducerContext, tournament_id: u32) -> Vec<TournamentParticipantTable> {
+    ctx.db
+        .tournament_participant()
+        .tournament_id()
+        .filter(tournament_id)
+        .collect()
+}
+
+fn matches(ctx: &ReducerContext, tournament_id: u32) -> Vec<TournamentMatchTable> {
+    ctx.db
+        .tournament_match()
+        .tournament_id()
+        .filter(tournament_id)
+        .collect()
+}
+
+// Seeds participants by rating, best first, keeping registration order for equal ratings.
+// Returns the participants' identities in seed order.
+fn assign_seeds(ctx: &ReducerContext, tournament_id: u32) -> Vec<Identity> {
+    let mut entrants = participants(ctx, tournament_id);
+    entrants.sort_by_key(|p| (-rating_for(ctx, p.identity), p.id));
+    entrants
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut participant)| {
+            participant.seed = Some(index as u32 + 1);
+            let identity = participant.identity;
+            ctx.db.tournament_participant().id().update(participant);
+            identity
+        })
+        .collect()
+}
+
+// Order of seeds down a bracket so the top two seeds can only meet in the final,
+// e.g. 1, 8, 4, 5, 2, 7, 3, 6 for eight slots
+fn bracket_order(size: u32) -> Vec<u32> {
+    let mut order = vec![1];
+    while (order.len() as u32) < size {
+        let slots = order.len() as u32 * 2;
+        order = order
+            .iter()
+            .flat_map(|seed| [*seed, slots + 1 - seed])
+            .collect();
+    }
+    order
+}
+
+fn create_elimination_bracket(
+    ctx: &ReducerContext,
+    tournament: &TournamentTable,
+    seeded: &[Identity],
+) -> Result<(), String> {
+    let size = (seeded.len() as u32).next_power_of_two();
+    let order = bracket_order(size);
+    let seed_identity = |seed: u32| seeded.get(seed as usize - 1).copied();
+    for (position, pair) in order.chunks(2).enumerate() {
+        let mut tournament_match = ctx.db.tournament_match().insert(TournamentMatchTable {
+            id: 0, // Auto-incremented
+            tournament_id: tournament.id,
+            round: 1,
+            position: position as u32,
+            player_one: seed_identity(pair[0]),
+            player_two: seed_identity(pair[1]),
+            game_id: None,
+            winner: None,
+            completed: false,
+        });
+        match (tournament_match.player_one, tournament_match.player_two) {
+            (Some(player_one), None) => {
+                // Byes go straight through to the next round
+                tournament_match.winner = Some(player_one);
+                tournament_match.completed = true;
+                ctx.db
+                    .tournament_match()
+                    .id()
+                    .update(tournament_match.clone());
+                advance_elimination_winner(ctx, tournament, &tournament_match, player_one)?;
+            }
+            _ => spawn_match_game(ctx, tournament, &mut tournament_match)?,
+        }
+    }
+    Ok(())
+}
+
+// Helper function to move an elimination winner into their next match, finishing the
+// tournament after the final
+fn advance_elimination_winner(
+    ctx: &ReducerContext,
+    tournament: &TournamentTable,
+    finished: &TournamentMatchTable,
+    winner: Identity,
+) -> Result<(), String> {
+    let size = (participants(ctx, tournament.id).len() as u32).next_power_of_two();
+    let total_rounds = size.trailing_zeros();
+    if finished.round >= total_rounds {
+        let mut tournament = tournament.clone();
+        tournament.status = TournamentStatus::Finished;
+        tournament.winner = Some(winner);
+        ctx.db.tournament().id().update(tournament);
+        return Ok(());
+    }
+
+    let round = finished.round + 1;
+    let position = finished.position / 2;
+    let existing = matches(ctx, tournament.id)
+        .into_iter()
+        .find(|m| m.round == round && m.position == position);
+    let mut next_match = match existing {
+        Some(next_match) => next_match,
+        None => ctx.db.tournament_match().insert(TournamentMatchTable {
+            id: 0, // Auto-incremented
+            tournament_id: tournament.id,
+            round,
+            position,
+            player_one: None,
+            player_two: None,
+            game_id: None,
+            winner: None,
+            completed: false,
+        }),
+    };
+    if finished.position.is_multiple_of(2) {
+        next_match.player_one = Some(winner);
+    } else {
+        next_match.player_two = Some(winner);
+    }
+    ctx.db.tournament_match().id().update(next_match.clone());
+
+    if round > tournament.current_round {
+        let mut tournament = tournament.clone();
+        tournament.current_round = round;
+        ctx.db.tournament().id().update(tournament);
+    }
+    match (next_match.player_one, next_match.player_two) {
+        (Some(_), Some(_)) => spawn_match_game(ctx, tournament, &mut next_match),
+        _ => Ok(()),
+    }
+}
+
+// A round robin pairing: the round it is played in, its position in that round and the
+// two players
+struct RoundRobinPairing {
+    round: u32,
+    position: u32,
+    player_one: Identity,
+    player_two: Identity,
+}
+
+// Pairs everyone against everyone using the circle method, one round per rotation.
+// With an odd number of players someone sits out each round.
+fn round_robin_pairings(seeded: &[Identity]) -> Vec<RoundRobinPairing> {
+    let mut slots: Vec<Option<Identity>> = seeded.iter().copied().map(Some).collect();
+    if slots.len() % 2 == 1 {
+        slots.push(None);
+    }
+    let slot_count = slots.len();
+    let mut pairings = Vec::new();
+    for round in 1..slot_count as u32 {
+        for position in 0..slot_count / 2 {
+            if let (Some(player_one), Some(player_two)) =
+                (slots[position], slots[slot_count - 1 - position])
+            {
+                pairings.push(RoundRobinPairing {
+                    round,
+                    position: position as u32,
+                    player_one,
+                    player_two,
+                });
+            }
+        }
+        slots[1..].rotate_right(1);
+    }
+    pairings
+}
+
+fn create_round_robin_schedule(
+    ctx: &ReducerContext,
+    tournament: &TournamentTable,
+    seeded: &[Identity],
+) {
+    for pairing in round_robin_pairings(seeded) {
+        ctx.db.tournament_match().insert(TournamentMatchTable {
+            id: 0, // Auto-incremented
+            tournament_id: tournament.id,
+            round: pairing.round,
+            position: pairing.position,
+            player_one: Some(pairing.player_one),
+            player_two: Some(pairing.player_two),
+            game_id: None,
+            winner: None,
+            completed: false,
+        });
+    }
+}
+
+fn start_round_robin_round(
+    ctx: &ReducerContext,
+    tournament: &TournamentTable,
+    round: u32,
+) -> Result<(), String> {
+    for mut tournament_match in matches(ctx, tournament.id)
+        .into_iter()
+        .filter(|m| m.round == round)
+    {
+        spawn_match_game(ctx, tournament, &mut tournament_match)?;
+    }
+    Ok(())
+}
+
+// Helper function to start the next round once every match in this one is done, or to
+// crown the player with the most wins (best seed on a tie) after the last round
+fn finish_round_robin_match(
+    ctx: &ReducerContext,
+    tournament: &TournamentTable,
+    finished: &TournamentMatchTable,
+) -> Result<(), String> {
+    let all_matches = matches(ctx, tournament.id);
+    if all_matches
+        .iter()
+        .any(|m| m.round == finished.round && !m.completed)
+    {
+        return Ok(());
+    }
+
+    let mut tournament = tournament.clone();
+    let next_round = finished.round + 1;
+    if all_matches.iter().any(|m| m.round == next_round) {
+        tournament.current_round = next_round;
+        ctx.db.tournament().id().update(tournament.clone());
+        return start_round_robin_round(ctx, &tournament, next_round);
+    }
+
+    tournament.status = TournamentStatus::Finished;
+    tournament.winner = participants(ctx, tournament.id)
+        .iter()
+        .min_by_key(|p| (std::cmp::Reverse(p.wins), p.seed))
+        .map(|p| p.identity);
+    ctx.db.tournament().id().update(tournament);
+    Ok(())
+}
+
+// Helper function to create and start the game for a match between its two players
+fn spawn_match_game(
+    ctx: &ReducerContext,
+    tournament: &TournamentTable,
+    tournament_match: &mut TournamentMatchTable,
+) -> Result<(), String> {
+    let (player_one, player_two) = match (tournament_match.player_one, tournament_match.player_two)
+    {
+        (Some(player_one), Some(player_two)) => (player_one, player_two),
+        _ => return Err("Tournament match is missing a player".to_string()),
+    };
+    let name = format!(
+        "{} - Round {} Match {}",
+        tournament.name,
+        tournament_match.round,
+        tournament_match.position + 1
+    );
+    let game = insert_game(ctx, name, tournament.settings.clone(), false);
+    add_identity_to_game(ctx, game.id, player_one)?;
+    add_identity_to_game(ctx, game.id, player_two)?;
+    start_game(ctx, game.id)?;
+    tournament_match.game_id = Some(game.id);
+    ctx.db
+        .tournament_match()
+        .id()
+        .update(tournament_match.clone());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identities(count: u8) -> Vec<Identity> {
+        (1..=count)
+            .map(|n| Identity::from_byte_array([n; 32]))
+            .collect()
+    }
+
+    // Every pair of players must meet exactly once and nobody plays twice in a round
+    fn check_round_robin(player_count: u8) {
+        let players = identities(player_count);
+        let pairings = round_robin_pairings(&players);
+        let slot_count = players.len() + players.len() % 2;
+
+        assert_eq!(pairings.len(), players.len() * (players.len() - 1) / 2);
+        for (i, a) in players.iter().enumerate() {
+            for b in &players[i + 1..] {
+                let meetings = pairings
+                    .iter()
+                    .filter(|p| {
+                        (p.player_one == *a && p.player_two == *b)
+                            || (p.player_one == *b && p.player_two == *a)
+                    })
+                    .count();
+                assert_eq!(meetings, 1);
+            }
+        }
+        for round in 1..slot_count as u32 {
+            let in_round: Vec<Identity> = pairings
+                .iter()
+                .filter(|p| p.round == round)
+                .flat_map(|p| [p.player_one, p.player_two])
+                .collect();
+            for player in &players {
+                assert!(in_round.iter().filter(|id| *id == player).count() <= 1);
+            }
+        }
+        assert!(pairings.iter().all(|p| p.round < slot_count as u32));
+    }
+
+    #[test]
+    fn bracket_order_keeps_top_seeds_apart() {
+        assert_eq!(bracket_order(1), vec![1]);
+        assert_eq!(bracket_order(2), vec![1, 2]);
+        assert_eq!(bracket_order(4), vec![1, 4, 2, 3]);
+        assert_eq!(bracket_order(8), vec![1, 8, 4, 5, 2, 7, 3, 6]);
+    }
+
+    #[test]
+    fn bracket_order_pairs_seeds_to_sum_one_more_than_size() {
+        let order = bracket_order(16);
+        assert_eq!(order.len(), 16);
+        assert!(order.chunks(2).all(|pair| pair[0] + pair[1] == 17));
+    }
+
+    #[test]
+    fn round_robin_with_even_players() {
+        check_round_robin(2);
+        check_round_robin(4);
+        check_round_robin(6);
+    }
+
+    #[test]
+    fn round_robin_with_odd_players_gives_each_a_bye() {
+        check_round_robin(3);
+        check_round_robin(5);
+
+        let players = identities(5);
+        let pairings = round_robin_pairings(&players);
+        for round in 1..=5 {
+            assert_eq!(pairings.iter().filter(|p| p.round == round).count(), 2);
+        }
+    }
+}
@@ -19,26 +19,42 @@ lazy_static::lazy_static! {
 }
 
 // Helper function to check if a word is valid
-pub fn is_word_valid(word: &str, trigram: &str, used_words: &[String]) -> Result<(), String> {
+pub fn is_word_valid(
+    word: &str,
+    trigram: &str,
+    used_words: &[String],
+    is_blocked: impl Fn(&str) -> bool,
+) -> Result<(), String> {
     if used_words.contains(&word.to_string()) {
         return Err("Word has already been used".to_string());
     }
+    if is_blocked(&word.to_uppercase()) {
+        return Err("Word is not allowed in this game".to_string());
+    }
     match TRIGRAM_MAP.get(&trigram.to_uppercase()) {
         Some(words) => {
             if words.contains(&word.to_uppercase()) {
                 return Ok(());
             }
-            return Err("Word not in dictionary".to_string());
+            Err("Word not in dictionary".to_string())
         }
-        None => return Err("Trigram not found".to_string()),
+        None => Err("Trigram not found".to_string()),
     }
 }
 
 // Helper function to get random long words containing a trigram
-pub fn get_example_words(trigram: &str, rng: &mut impl rand::RngCore) -> Vec<String> {
+pub fn get_example_words(
+    trigram: &str,
+    is_blocked: impl Fn(&str) -> bool,
+    rng: &mut impl rand::RngCore,
+) -> Vec<String> {
     if let Some(words) = TRIGRAM_MAP.get(&trigram.to_uppercase()) {
-        // Filter for words longer than 10 characters
-        let long_words: Vec<String> = words.iter().filter(|w| w.len() > 10).cloned().collect();
+        // Filter for words longer than 10 characters that aren't blocked
+        let long_words: Vec<String> = words
+            .iter()
+            .filter(|w| w.len() > 10 && !is_blocked(&w.to_uppercase()))
+            .cloned()
+            .collect();
 
         if long_words.is_empty() {
             return Vec::new();
@@ -73,3 +89,87 @@ pub fn get_available_trigrams(used_trigrams: &[String]) -> Vec<String> {
         .map(|(t, _)| t.clone())
         .collect()
 }
+
+// Helper function to get bonus points for letters that are hard to fit into words
+fn rare_letter_points(letter: char) -> u32 {
+    match letter {
+        'Q' | 'Z' => 5,
+        'J' | 'X' => 4,
+        'K' => 2,
+        'F' | 'H' | 'V' | 'W' | 'Y' => 1,
+        _ => 0,
+    }
+}
+
+// Helper function to get bonus points for trigrams that few dictionary words contain
+fn trigram_difficulty_points(trigram: &str) -> u32 {
+    match TRIGRAM_MAP.get(&trigram.to_uppercase()) {
+        Some(words) => difficulty_points(words.len()),
+        None => 0,
+    }
+}
+
+// Helper function to turn the number of words containing a trigram into bonus points
+fn difficulty_points(word_count: usize) -> u32 {
+    match word_count {
+        0..=400 => 3,
+        401..=800 => 2,
+        801..=1600 => 1,
+        _ => 0,
+    }
+}
+
+// Helper function to score a word's length and rare letters
+fn word_points(word: &str) -> u32 {
+    let word = word.to_uppercase();
+    let length_points = word.chars().count() as u32;
+    let letter_points: u32 = word.chars().map(rare_letter_points).sum();
+    length_points + letter_points
+}
+
+// Helper function to score an accepted word by length, rare letters and trigram difficulty
+pub fn score_word(word: &str, trigram: &str) -> u32 {
+    word_points(word) + trigram_difficulty_points(trigram)
+}
+
+// Trigrams with at most this many words are used once a game goes to overtime
+const HARD_TRIGRAM_MAX_WORDS: usize = 400;
+
+// Helper function to get unused trigrams that are still playable but have few words
+pub fn get_hard_trigrams(used_trigrams: &[String]) -> Vec<String> {
+    TRIGRAM_MAP
+        .iter()
+        .filter(|(t, words)| {
+            !used_trigrams.contains(t) && words.len() > 200 && words.len() <= HARD_TRIGRAM_MAX_WORDS
+        })
+        .map(|(t, _)| t.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_score_their_length() {
+        assert_eq!(word_points("bean"), 4);
+        assert_eq!(word_points("STATION"), 7);
+    }
+
+    #[test]
+    fn rare_letters_score_extra() {
+        // 4 letters, plus 5 for the Q and 1 for the Y
+        assert_eq!(word_points("QUAY"), 10);
+        // 5 letters, plus 5 for the Z, 2 for the K and 1 for the Y
+        assert_eq!(word_points("zonky"), 13);
+    }
+
+    #[test]
+    fn rarer_trigrams_score_more() {
+        assert_eq!(difficulty_points(250), 3);
+        assert_eq!(difficulty_points(400), 3);
+        assert_eq!(difficulty_points(401), 2);
+        assert_eq!(difficulty_points(1600), 1);
+        assert_eq!(difficulty_points(5000), 0);
+    }
+}
@@ -0,0 +1,160 @@
+use spacetimedb::{Identity, ReducerContext, Table};
+
+use crate::content_filter::require_admin;
+use crate::player_info;
+use crate::profanity::contains_profanity;
+
+pub const MIN_USERNAME_LENGTH: usize = 3;
+pub const MAX_USERNAME_LENGTH: usize = 20;
+const DEFAULT_RENAME_COOLDOWN_SECONDS: u32 = 60 * 60;
+const MAX_RENAME_COOLDOWN_SECONDS: u32 = 30 * 24 * 60 * 60;
+
+// Module-wide username rules. Admins can change them with set_username_policy.
+#[spacetimedb::table(name = username_policy)]
+pub struct UsernamePolicy {
+    #[primary_key]
+    pub id: u32, // Always 0
+    pub require_unique: bool, // Reject names another player already has, ignoring case
+    pub rename_cooldown_seconds: u32, // How long a player must wait between renames
+}
+
+// Helper function to insert the default username rules
+pub fn seed_username_policy(ctx: &ReducerContext) {
+    ctx.db.username_policy().insert(UsernamePolicy {
+        id: 0,
+        require_unique: true,
+        rename_cooldown_seconds: DEFAULT_RENAME_COOLDOWN_SECONDS,
+    });
+}
+
+// Helper function to read the username rules, falling back to the defaults
+pub fn username_policy_or_default(ctx: &ReducerContext) -> UsernamePolicy {
+    ctx.db
+        .username_policy()
+        .id()
+        .find(0)
+        .unwrap_or(UsernamePolicy {
+            id: 0,
+            require_unique: true,
+            rename_cooldown_seconds: DEFAULT_RENAME_COOLDOWN_SECONDS,
+        })
+}
+
+#[spacetimedb::reducer]
+pub fn set_username_policy(
+    ctx: &ReducerContext,
+    require_unique: bool,
+    rename_cooldown_seconds: u32,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    if rename_cooldown_seconds > MAX_RENAME_COOLDOWN_SECONDS {
+        return Err(format!(
+            "Rename cooldown can be at most {} seconds",
+            MAX_RENAME_COOLDOWN_SECONDS
+        ));
+    }
+    let policy = UsernamePolicy {
+        id: 0,
+        require_unique,
+        rename_cooldown_seconds,
+    };
+    if ctx.db.username_policy().id().find(0).is_some() {
+        ctx.db.username_policy().id().update(policy);
+    } else {
+        ctx.db.username_policy().insert(policy);
+    }
+    Ok(())
+}
+
+// Helper function to get the key names are compared by for uniqueness
+pub fn username_key(username: &str) -> String {
+    username.to_lowercase()
+}
+
+// Trims a requested username and checks it is allowed, returning the name to store
+pub fn validate_username(username: &str) -> Result<String, String> {
+    let username = username.trim();
+    let length = username.chars().count();
+    if !(MIN_USERNAME_LENGTH..=MAX_USERNAME_LENGTH).contains(&length) {
+        return Err(format!(
+            "Username must be between {} and {} characters",
+            MIN_USERNAME_LENGTH, MAX_USERNAME_LENGTH
+        ));
+    }
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '_' || c == '-' || c == '.')
+    {
+        return Err(
+            "Username can only contain letters, numbers, spaces, '_', '-' and '.'".to_string(),
+        );
+    }
+    if username.contains("  ") {
+        return Err("Username cannot contain repeated spaces".to_string());
+    }
+    if contains_profanity(username) {
+        return Err("Username is not allowed".to_string());
+    }
+    Ok(username.to_string())
+}
+
+// Helper function to check no other player already goes by this name
+pub fn check_username_available(
+    ctx: &ReducerContext,
+    identity: Identity,
+    username: &str,
+) -> Result<(), String> {
+    let key = username_key(username);
+    let taken = ctx
+        .db
+        .player_info()
+        .username_key()
+        .filter(&key)
+        .any(|player| player.identity != identity);
+    if taken {
+        return Err("Username is already taken".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trims_and_accepts_valid_names() {
+        assert_eq!(validate_username("  Bomber  "), Ok("Bomber".to_string()));
+        assert_eq!(
+            validate_username("Kim Ash_Itoh-2.0"),
+            Ok("Kim Ash_Itoh-2.0".to_string())
+        );
+    }
+
+    #[test]
+    fn checks_length_after_trimming() {
+        assert!(validate_username("ab").is_err());
+        assert!(validate_username("   ab   ").is_err());
+        assert!(validate_username(&"a".repeat(MIN_USERNAME_LENGTH)).is_ok());
+        assert!(validate_username(&"a".repeat(MAX_USERNAME_LENGTH)).is_ok());
+        assert!(validate_username(&"a".repeat(MAX_USERNAME_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn rejects_other_characters() {
+        assert!(validate_username("bomb!").is_err());
+        assert!(validate_username("émile").is_err());
+        assert!(validate_username("tab\tname").is_err());
+    }
+
+    #[test]
+    fn rejects_repeated_spaces() {
+        assert!(validate_username("two  spaces").is_err());
+    }
+
+    #[test]
+    fn rejects_profanity_but_not_real_names() {
+        assert!(validate_username("shithead").is_err());
+        assert!(validate_username("Nazir").is_ok());
+        assert!(validate_username("Fagan").is_ok());
+    }
+}
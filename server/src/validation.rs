@@ -0,0 +1,423 @@
+use std::fmt;
+
+use crate::{PlayerHandicap, PointsGoal, SettingsState, TurnLogicMode, WinCondition};
+
+pub const MAX_TURN_TIMEOUT_SECONDS: u32 = 120;
+pub const MAX_STARTING_LIVES: u32 = 20;
+pub const MAX_ROUNDS: u32 = 100;
+pub const MAX_CHESS_CLOCK_BANK_SECONDS: u32 = 600;
+pub const MAX_HANDICAP_SECONDS: u32 = 30;
+pub const MAX_HANDICAP_LIVES: u32 = 5;
+pub const MAX_HANDICAP_FREE_LETTERS: u32 = 10;
+pub const MAX_COUNTDOWN_SECONDS: u32 = 30;
+pub const MAX_READY_CHECK_SECONDS: u32 = 120;
+pub const MAX_SERIES_BEST_OF: u32 = 7;
+pub const MAX_PLAYERS_LIMIT: u32 = 16;
+
+pub enum SettingsError {
+    TurnTimeoutZero,
+    TurnTimeoutTooLong,
+    StartingLivesZero,
+    StartingLivesTooHigh,
+    StartingLivesNeedLastPlayerStanding,
+    TargetScoreZero,
+    PointsRoundsZero,
+    PointsRoundsTooHigh,
+    BonusLetterWordCountZero,
+    BonusLettersUnusedInPoints,
+    MaxRoundsZero,
+    MaxRoundsTooHigh,
+    MaxRoundsBeforePointsRounds { max_rounds: u32, points_rounds: u32 },
+    MatchTimeLimitZero,
+    OvertimeNeedsLastPlayerStanding,
+    WordTurnEffectsNeedClassic,
+    ChessClockBankZero,
+    ChessClockBankTooLong,
+    ChessClockNeedsClassicLastPlayerStanding,
+    HandicapSecondsTooHigh,
+    HandicapLivesTooHigh,
+    HandicapFreeLettersTooHigh,
+    CountdownTooLong,
+    ReadyCheckZero,
+    ReadyCheckTooLong,
+    SeriesLengthInvalid,
+    MaxPlayersOutOfRange,
+    MorePlayersThanSeats,
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingsError::TurnTimeoutZero => {
+                write!(f, "Turn timeout must be greater than 0 seconds")
+            }
+            SettingsError::TurnTimeoutTooLong => write!(
+                f,
+                "Turn timeout can be at most {} seconds",
+                MAX_TURN_TIMEOUT_SECONDS
+            ),
+            SettingsError::StartingLivesZero => write!(f, "Starting lives must be greater than 0"),
+            SettingsError::StartingLivesTooHigh => {
+                write!(f, "Starting lives can be at most {}", MAX_STARTING_LIVES)
+            }
+            SettingsError::StartingLivesNeedLastPlayerStanding => {
+                write!(f, "Starting lives only apply to Last Player Standing")
+            }
+            SettingsError::TargetScoreZero => write!(f, "Target score must be greater than 0"),
+            SettingsError::PointsRoundsZero => {
+                write!(f, "Number of rounds must be greater than 0")
+            }
+            SettingsError::PointsRoundsTooHigh => {
+                write!(f, "Number of rounds can be at most {}", MAX_ROUNDS)
+            }
+            SettingsError::BonusLetterWordCountZero => {
+                write!(f, "Bonus letter word count must be at least 1")
+            }
+            SettingsError::BonusLettersUnusedInPoints => {
+                write!(f, "Bonus letters do nothing in Points games")
+            }
+            SettingsError::MaxRoundsZero => write!(f, "Maximum rounds must be at least 1"),
+            SettingsError::MaxRoundsTooHigh => {
+                write!(f, "Maximum rounds can be at most {}", MAX_ROUNDS)
+            }
+            SettingsError::MaxRoundsBeforePointsRounds {
+                max_rounds,
+                points_rounds,
+            } => write!(
+                f,
+                "Maximum rounds ({}) would end the game before the {} rounds of Points play",
+                max_rounds, points_rounds
+            ),
+            SettingsError::MatchTimeLimitZero => {
+                write!(f, "Match time limit must be greater than 0 seconds")
+            }
+            SettingsError::OvertimeNeedsLastPlayerStanding => {
+                write!(f, "Overtime only applies to Last Player Standing")
+            }
+            SettingsError::WordTurnEffectsNeedClassic => {
+                write!(f, "Word turn effects only apply to Classic turns")
+            }
+            SettingsError::ChessClockBankZero => write!(f, "Time bank must be greater than 0"),
+            SettingsError::ChessClockBankTooLong => write!(
+                f,
+                "Time bank can be at most {} seconds",
+                MAX_CHESS_CLOCK_BANK_SECONDS
+            ),
+            SettingsError::ChessClockNeedsClassicLastPlayerStanding => write!(
+                f,
+                "The chess clock only applies to Classic Last Player Standing games"
+            ),
+            SettingsError::HandicapSecondsTooHigh => {
+                write!(f, "Extra seconds can be at most {}", MAX_HANDICAP_SECONDS)
+            }
+            SettingsError::HandicapLivesTooHigh => {
+                write!(f, "Extra lives can be at most {}", MAX_HANDICAP_LIVES)
+            }
+            SettingsError::HandicapFreeLettersTooHigh => write!(
+                f,
+                "Starting free letters can be at most {}",
+                MAX_HANDICAP_FREE_LETTERS
+            ),
+            SettingsError::CountdownTooLong => {
+                write!(
+                    f,
+                    "Countdown can be at most {} seconds",
+                    MAX_COUNTDOWN_SECONDS
+                )
+            }
+            SettingsError::ReadyCheckZero => {
+                write!(f, "Ready check must be greater than 0 seconds")
+            }
+            SettingsError::ReadyCheckTooLong => write!(
+                f,
+                "Ready check can be at most {} seconds",
+                MAX_READY_CHECK_SECONDS
+            ),
+            SettingsError::SeriesLengthInvalid => write!(
+                f,
+                "A series must be best of an odd number of games from 3 to {}",
+                MAX_SERIES_BEST_OF
+            ),
+            SettingsError::MaxPlayersOutOfRange => write!(
+                f,
+                "Maximum players must be between 2 and {}",
+                MAX_PLAYERS_LIMIT
+            ),
+            SettingsError::MorePlayersThanSeats => {
+                write!(f, "More players are seated than the maximum allows")
+            }
+        }
+    }
+}
+
+// Checks a game's settings on their own and against each other. Every settings reducer
+// runs this on the settings it is about to save, and start_game runs it again.
+pub fn validate_settings(settings: &SettingsState) -> Result<(), SettingsError> {
+    if settings.turn_timeout_seconds == 0 {
+        return Err(SettingsError::TurnTimeoutZero);
+    }
+    if settings.turn_timeout_seconds > MAX_TURN_TIMEOUT_SECONDS {
+        return Err(SettingsError::TurnTimeoutTooLong);
+    }
+
+    let is_last_player_standing = match settings.win_condition {
+        WinCondition::LastPlayerStanding { starting_lives } => {
+            if starting_lives == 0 {
+                return Err(SettingsError::StartingLivesZero);
+            }
+            if starting_lives > MAX_STARTING_LIVES {
+                return Err(SettingsError::StartingLivesTooHigh);
+            }
+            true
+        }
+        WinCondition::UseAllLetters => false,
+        WinCondition::Points { goal } => {
+            match goal {
+                PointsGoal::TargetScore(0) => return Err(SettingsError::TargetScoreZero),
+                PointsGoal::Rounds(0) => return Err(SettingsError::PointsRoundsZero),
+                PointsGoal::Rounds(points_rounds) if points_rounds > MAX_ROUNDS => {
+                    return Err(SettingsError::PointsRoundsTooHigh)
+                }
+                PointsGoal::Rounds(points_rounds) => {
+                    if let Some(max_rounds) = settings.max_rounds {
+                        if max_rounds < points_rounds {
+                            return Err(SettingsError::MaxRoundsBeforePointsRounds {
+                                max_rounds,
+                                points_rounds,
+                            });
+                        }
+                    }
+                }
+                PointsGoal::TargetScore(_) => {}
+            }
+            false
+        }
+    };
+    let is_classic = match settings.turn_logic_mode {
+        TurnLogicMode::Classic => true,
+        TurnLogicMode::Simultaneous => false,
+    };
+
+    // Free letters only count towards using the whole alphabet, which Points ignores
+    match (settings.bonus_letter_word_count, settings.win_condition) {
+        (Some(0), _) => return Err(SettingsError::BonusLetterWordCountZero),
+        (Some(_), WinCondition::Points { .. }) => {
+            return Err(SettingsError::BonusLettersUnusedInPoints)
+        }
+        _ => {}
+    }
+    match settings.max_rounds {
+        Some(0) => return Err(SettingsError::MaxRoundsZero),
+        Some(max_rounds) if max_rounds > MAX_ROUNDS => return Err(SettingsError::MaxRoundsTooHigh),
+        _ => {}
+    }
+    if let Some(0) = settings.match_time_limit_seconds {
+        return Err(SettingsError::MatchTimeLimitZero);
+    }
+    if settings.countdown_seconds > MAX_COUNTDOWN_SECONDS {
+        return Err(SettingsError::CountdownTooLong);
+    }
+    match settings.ready_check_seconds {
+        Some(0) => return Err(SettingsError::ReadyCheckZero),
+        Some(seconds) if seconds > MAX_READY_CHECK_SECONDS => {
+            return Err(SettingsError::ReadyCheckTooLong)
+        }
+        _ => {}
+    }
+    if let Some(best_of) = settings.series_best_of {
+        if !(3..=MAX_SERIES_BEST_OF).contains(&best_of) || best_of.is_multiple_of(2) {
+            return Err(SettingsError::SeriesLengthInvalid);
+        }
+    }
+    if settings.overtime_enabled && !is_last_player_standing {
+        return Err(SettingsError::OvertimeNeedsLastPlayerStanding);
+    }
+    if settings.word_turn_effects && !is_classic {
+        return Err(SettingsError::WordTurnEffectsNeedClassic);
+    }
+    if let Some(clock) = settings.chess_clock {
+        if clock.bank_seconds == 0 {
+            return Err(SettingsError::ChessClockBankZero);
+        }
+        if clock.bank_seconds > MAX_CHESS_CLOCK_BANK_SECONDS {
+            return Err(SettingsError::ChessClockBankTooLong);
+        }
+        if !is_classic || !is_last_player_standing {
+            return Err(SettingsError::ChessClockNeedsClassicLastPlayerStanding);
+        }
+    }
+
+    if !(2..=MAX_PLAYERS_LIMIT).contains(&settings.max_players) {
+        return Err(SettingsError::MaxPlayersOutOfRange);
+    }
+    if settings.players.len() > settings.max_players as usize {
+        return Err(SettingsError::MorePlayersThanSeats);
+    }
+
+    for player in &settings.players {
+        validate_handicap(&player.handicap)?;
+    }
+    Ok(())
+}
+
+// Checks a handicap on its own, before it is added to a player's lives and time
+fn validate_handicap(handicap: &PlayerHandicap) -> Result<(), SettingsError> {
+    if handicap.extra_seconds > MAX_HANDICAP_SECONDS {
+        return Err(SettingsError::HandicapSecondsTooHigh);
+    }
+    if handicap.extra_lives > MAX_HANDICAP_LIVES {
+        return Err(SettingsError::HandicapLivesTooHigh);
+    }
+    if handicap.starting_free_letters > MAX_HANDICAP_FREE_LETTERS {
+        return Err(SettingsError::HandicapFreeLettersTooHigh);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{default_settings, ChessClockSettings, ClockExpiry, NO_HANDICAP};
+
+    #[test]
+    fn default_settings_are_valid() {
+        assert!(validate_settings(&default_settings()).is_ok());
+    }
+
+    #[test]
+    fn turn_timeout_must_be_in_range() {
+        let mut settings = default_settings();
+        settings.turn_timeout_seconds = 0;
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::TurnTimeoutZero)
+        ));
+        settings.turn_timeout_seconds = MAX_TURN_TIMEOUT_SECONDS + 1;
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::TurnTimeoutTooLong)
+        ));
+        settings.turn_timeout_seconds = MAX_TURN_TIMEOUT_SECONDS;
+        assert!(validate_settings(&settings).is_ok());
+    }
+
+    #[test]
+    fn starting_lives_must_be_in_range() {
+        let mut settings = default_settings();
+        settings.win_condition = WinCondition::LastPlayerStanding { starting_lives: 0 };
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::StartingLivesZero)
+        ));
+        settings.win_condition = WinCondition::LastPlayerStanding {
+            starting_lives: MAX_STARTING_LIVES + 1,
+        };
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::StartingLivesTooHigh)
+        ));
+    }
+
+    #[test]
+    fn round_limits_must_fit_points_rounds() {
+        let mut settings = default_settings();
+        settings.win_condition = WinCondition::Points {
+            goal: PointsGoal::Rounds(10),
+        };
+        settings.max_rounds = Some(5);
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::MaxRoundsBeforePointsRounds {
+                max_rounds: 5,
+                points_rounds: 10
+            })
+        ));
+        settings.max_rounds = Some(10);
+        assert!(validate_settings(&settings).is_ok());
+        settings.max_rounds = Some(MAX_ROUNDS + 1);
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::MaxRoundsTooHigh)
+        ));
+        settings.max_rounds = None;
+        settings.win_condition = WinCondition::Points {
+            goal: PointsGoal::Rounds(MAX_ROUNDS + 1),
+        };
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::PointsRoundsTooHigh)
+        ));
+    }
+
+    #[test]
+    fn bonus_letters_are_rejected_in_points() {
+        let mut settings = default_settings();
+        settings.win_condition = WinCondition::Points {
+            goal: PointsGoal::TargetScore(50),
+        };
+        settings.bonus_letter_word_count = Some(3);
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::BonusLettersUnusedInPoints)
+        ));
+    }
+
+    #[test]
+    fn chess_clock_needs_classic_last_player_standing() {
+        let mut settings = default_settings();
+        settings.chess_clock = Some(ChessClockSettings {
+            bank_seconds: 60,
+            increment_seconds: 2,
+            on_empty: ClockExpiry::Eliminate,
+        });
+        assert!(validate_settings(&settings).is_ok());
+        settings.turn_logic_mode = TurnLogicMode::Simultaneous;
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::ChessClockNeedsClassicLastPlayerStanding)
+        ));
+        settings.turn_logic_mode = TurnLogicMode::Classic;
+        settings.chess_clock = Some(ChessClockSettings {
+            bank_seconds: MAX_CHESS_CLOCK_BANK_SECONDS + 1,
+            increment_seconds: 2,
+            on_empty: ClockExpiry::Eliminate,
+        });
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::ChessClockBankTooLong)
+        ));
+    }
+
+    #[test]
+    fn series_must_be_an_odd_best_of() {
+        let mut settings = default_settings();
+        for best_of in [1, 4, MAX_SERIES_BEST_OF + 2] {
+            settings.series_best_of = Some(best_of);
+            assert!(matches!(
+                validate_settings(&settings),
+                Err(SettingsError::SeriesLengthInvalid)
+            ));
+        }
+        settings.series_best_of = Some(3);
+        assert!(validate_settings(&settings).is_ok());
+    }
+
+    #[test]
+    fn handicaps_are_capped() {
+        assert!(validate_handicap(&NO_HANDICAP).is_ok());
+        assert!(matches!(
+            validate_handicap(&PlayerHandicap {
+                extra_seconds: MAX_HANDICAP_SECONDS + 1,
+                ..NO_HANDICAP
+            }),
+            Err(SettingsError::HandicapSecondsTooHigh)
+        ));
+        assert!(matches!(
+            validate_handicap(&PlayerHandicap {
+                extra_lives: u32::MAX,
+                ..NO_HANDICAP
+            }),
+            Err(SettingsError::HandicapLivesTooHigh)
+        ));
+    }
+}